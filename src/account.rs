@@ -1,12 +1,23 @@
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use bc_envelope::prelude::*;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use crate::{
-    Address, BlockHash, BlockHeight, Indexed, NoQuotesDebugOption, TxId,
-    envelope_indexed_objects_for_predicate, orchard::OrchardSentOutput, sapling::SaplingSentOutput,
+    Address, BlockHash, BlockHeight, Checkpoint, DerivationInfo, DiffEntry, DiffKind, Indexed,
+    NoQuotesDebugOption, NonHardenedChildIndex, ProtocolAddress, TxId, diff_keyed,
+    orchard::OrchardSentOutput,
+    predicate::{
+        ADDRESS, BIRTHDAY_BLOCK, BIRTHDAY_HEIGHT, NAME, ORCHARD_SENT_OUTPUT, RELEVANT_TRANSACTIONS,
+        SAPLING_SENT_OUTPUT, ZIP32_ACCOUNT_ID,
+    },
+    sapling::SaplingSentOutput,
+    set_indexes,
 };
 
+/// The number of blocks below the export height within which an account's birthday block must
+/// be verified against the main chain by the restoring wallet.
+pub const BIRTHDAY_VERIFICATION_WINDOW: u32 = 100;
+
 /// A logical grouping of addresses and transaction history within a wallet.
 ///
 /// `Account` represents a distinct subdivision of wallet functionality, similar to how
@@ -164,6 +175,54 @@ impl Account {
         self.birthday_block = birthday_block;
     }
 
+    /// Returns the birthday height and birthday block as a [`Checkpoint`], or `None` unless
+    /// both are known.
+    pub fn birthday_checkpoint(&self) -> Option<Checkpoint> {
+        Some(Checkpoint::new(self.birthday_height?, self.birthday_block?))
+    }
+
+    /// Returns `true` if the restoring wallet should verify that this account's birthday block
+    /// exists within the main chain.
+    ///
+    /// This is the case when the birthday height is known and lies within
+    /// [`BIRTHDAY_VERIFICATION_WINDOW`] blocks of `export_height`, the export height of the
+    /// [`Zewif`](crate::Zewif) containing this account; blocks that recent may yet be reorged.
+    pub fn requires_birthday_verification(&self, export_height: BlockHeight) -> bool {
+        self.birthday_height
+            .is_some_and(|birthday| export_height - birthday <= BIRTHDAY_VERIFICATION_WINDOW)
+    }
+
+    /// Returns `true` if the birthday height and birthday block are either both known or both
+    /// unknown.
+    ///
+    /// A birthday block is only meaningful together with the height it was mined at, so a
+    /// lone value usually indicates data lost during export. See
+    /// [`validate_birthday`](Self::validate_birthday) for a descriptive error.
+    pub fn has_consistent_birthday(&self) -> bool {
+        self.birthday_height.is_some() == self.birthday_block.is_some()
+    }
+
+    /// Checks that the birthday height and birthday block are either both known or both
+    /// unknown.
+    ///
+    /// # Errors
+    /// Returns an error naming the missing value if only one of the two is present.
+    pub fn validate_birthday(&self) -> Result<()> {
+        match (self.birthday_height, self.birthday_block) {
+            (Some(height), None) => bail!(
+                "account {} has birthday height {} but no birthday block",
+                self.index,
+                height
+            ),
+            (None, Some(block)) => bail!(
+                "account {} has birthday block {} but no birthday height",
+                self.index,
+                block
+            ),
+            _ => Ok(()),
+        }
+    }
+
     pub fn zip32_account_id(&self) -> Option<u32> {
         self.zip32_account_id
     }
@@ -185,6 +244,58 @@ impl Account {
         self.addresses.push(address);
     }
 
+    /// Iterates over the account's transparent addresses.
+    pub fn transparent_addresses(&self) -> impl Iterator<Item = &Address> {
+        self.addresses
+            .iter()
+            .filter(|address| address.address().is_transparent())
+    }
+
+    /// Iterates over the account's shielded (Sapling) addresses.
+    ///
+    /// Unified addresses are not included, even if they have a shielded receiver; see
+    /// [`unified_addresses`](Self::unified_addresses).
+    pub fn shielded_addresses(&self) -> impl Iterator<Item = &Address> {
+        self.addresses
+            .iter()
+            .filter(|address| address.address().is_sapling())
+    }
+
+    /// Iterates over the account's unified addresses.
+    pub fn unified_addresses(&self) -> impl Iterator<Item = &Address> {
+        self.addresses
+            .iter()
+            .filter(|address| address.address().is_unified())
+    }
+
+    /// Returns the address indexes used by this account's HD-derived addresses, sorted and
+    /// without duplicates.
+    ///
+    /// The index is taken from each address's [`DerivationInfo`] where present, and otherwise
+    /// parsed from its HD derivation path. Addresses with neither, or with a path that does not
+    /// end in two non-hardened components, are skipped. External and change addresses are
+    /// combined, so the largest index bounds the gap-limit scan for both chains.
+    pub fn used_nonhardened_indices(&self) -> Vec<NonHardenedChildIndex> {
+        let parse =
+            |path: Option<&str>| path.and_then(|p| DerivationInfo::from_path_string(p).ok());
+        let mut indices: Vec<NonHardenedChildIndex> = self
+            .addresses
+            .iter()
+            .filter_map(|address| match address.address() {
+                ProtocolAddress::Transparent(t) => t.derivation_info().copied(),
+                ProtocolAddress::Sapling(s) => s
+                    .derivation_info()
+                    .copied()
+                    .or_else(|| parse(s.hd_derivation_path())),
+                ProtocolAddress::Unified(u) => parse(u.hd_derivation_path()),
+            })
+            .map(|info| info.address_index())
+            .collect();
+        indices.sort();
+        indices.dedup();
+        indices
+    }
+
     pub fn relevant_transactions(&self) -> &HashSet<TxId> {
         &self.relevant_transactions
     }
@@ -205,6 +316,14 @@ impl Account {
         self.sapling_sent_outputs.len()
     }
 
+    /// Returns the Sapling sent output whose [`Indexed`] index is `index`.
+    ///
+    /// Indexes normally match vector positions, so the output at position `index` is
+    /// checked first; if its stored index differs, the outputs are searched.
+    pub fn sapling_sent_output(&self, index: usize) -> Option<&SaplingSentOutput> {
+        find_indexed(&self.sapling_sent_outputs, index)
+    }
+
     pub fn add_sapling_sent_output(&mut self, mut output: SaplingSentOutput) {
         output.set_index(self.sapling_sent_outputs.len());
         self.sapling_sent_outputs.push(output);
@@ -218,10 +337,205 @@ impl Account {
         self.orchard_sent_outputs.len()
     }
 
+    /// Returns the Orchard sent output whose [`Indexed`] index is `index`.
+    ///
+    /// See [`sapling_sent_output`](Self::sapling_sent_output).
+    pub fn orchard_sent_output(&self, index: usize) -> Option<&OrchardSentOutput> {
+        find_indexed(&self.orchard_sent_outputs, index)
+    }
+
     pub fn add_orchard_sent_output(&mut self, mut output: OrchardSentOutput) {
         output.set_index(self.orchard_sent_outputs.len());
         self.orchard_sent_outputs.push(output);
     }
+
+    /// Puts the account's ordered collections into a canonical order, so that two accounts
+    /// with the same contents produce identical envelope digests regardless of the order in
+    /// which their addresses and sent outputs were added.
+    ///
+    /// Addresses are sorted by their string form, and sent outputs by recipient address, value
+    /// and memo; all are then re-indexed from 0.
+    ///
+    /// Serialization never reorders an account by itself: envelope assertions are already
+    /// emitted in digest order, but each address and sent output carries its index, so the
+    /// stored order is part of the serialized data. Call this before serializing when that
+    /// order carries no meaning and a reproducible digest is wanted.
+    pub fn canonicalize(&mut self) {
+        self.addresses.sort_by_key(|address| address.as_string());
+        self.sapling_sent_outputs.sort_by_key(|output| {
+            let memo = output.memo().map(|memo| memo.to_vec());
+            (output.recipient_address().to_string(), output.value(), memo)
+        });
+        self.orchard_sent_outputs.sort_by_key(|output| {
+            let memo = output.memo().map(|memo| memo.to_vec());
+            (output.recipient_address().to_string(), output.value(), memo)
+        });
+        self.addresses = set_indexes(std::mem::take(&mut self.addresses));
+        self.sapling_sent_outputs = set_indexes(std::mem::take(&mut self.sapling_sent_outputs));
+        self.orchard_sent_outputs = set_indexes(std::mem::take(&mut self.orchard_sent_outputs));
+    }
+
+    /// Removes addresses whose string form repeats an earlier address in this account,
+    /// returning the number removed.
+    ///
+    /// The first occurrence is kept. If it has an empty name or no purpose, the name or purpose
+    /// of a removed duplicate is carried over. The remaining addresses are re-indexed from 0.
+    pub fn dedup_addresses(&mut self) -> usize {
+        let before = self.addresses.len();
+        let mut kept: Vec<Address> = Vec::with_capacity(before);
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        for address in std::mem::take(&mut self.addresses) {
+            match positions.get(&address.as_string()) {
+                Some(&position) => {
+                    let first = &mut kept[position];
+                    if first.name().is_empty() && !address.name().is_empty() {
+                        first.set_name(address.name().to_string());
+                    }
+                    if let (None, Some(purpose)) = (first.purpose(), address.purpose()) {
+                        first.set_purpose(purpose.to_string());
+                    }
+                }
+                None => {
+                    positions.insert(address.as_string(), kept.len());
+                    kept.push(address);
+                }
+            }
+        }
+        self.addresses = set_indexes(kept);
+        before - self.addresses.len()
+    }
+
+    /// Folds another account describing the same logical account into this one.
+    ///
+    /// - Addresses from `other` are appended unless an address with the same string form is
+    ///   already present, including one appended earlier from `other`.
+    /// - Relevant transactions are unioned.
+    /// - Sent outputs from `other` are appended and re-indexed.
+    /// - The earlier of the two birthday heights is kept, together with its birthday block.
+    /// - The ZIP 32 account ID is taken from `other` if this account has none.
+    ///
+    /// This account's name and attachments are retained; those of `other` are discarded.
+    ///
+    /// # Errors
+    /// Returns an error, leaving this account unchanged, if both accounts have ZIP 32 account
+    /// IDs and they differ.
+    pub fn merge_from(&mut self, other: Account) -> Result<()> {
+        match (self.zip32_account_id, other.zip32_account_id) {
+            (Some(a), Some(b)) if a != b => {
+                bail!(
+                    "Cannot merge accounts with different ZIP 32 account IDs: {} and {}",
+                    a,
+                    b
+                )
+            }
+            (None, Some(b)) => self.zip32_account_id = Some(b),
+            _ => {}
+        }
+
+        let other_is_earlier = match (self.birthday_height, other.birthday_height) {
+            (Some(a), Some(b)) => b < a,
+            (None, Some(_)) => true,
+            _ => false,
+        };
+        if other_is_earlier {
+            self.birthday_height = other.birthday_height;
+            self.birthday_block = other.birthday_block;
+        }
+
+        let mut existing: HashSet<String> = self.addresses.iter().map(|a| a.as_string()).collect();
+        for address in other.addresses {
+            if existing.insert(address.as_string()) {
+                self.add_address(address);
+            }
+        }
+
+        self.relevant_transactions
+            .extend(other.relevant_transactions);
+
+        for output in other.sapling_sent_outputs {
+            self.add_sapling_sent_output(output);
+        }
+        for output in other.orchard_sent_outputs {
+            self.add_orchard_sent_output(output);
+        }
+
+        Ok(())
+    }
+
+    /// Records the differences between this account and `other` under `path`, as part of
+    /// [`Zewif::diff`](crate::Zewif::diff).
+    ///
+    /// Addresses are compared by their string form, ignoring their indexes; when an address
+    /// appears more than once, its copies are paired in index order and any surplus copies
+    /// are reported as added or removed. Relevant transactions are compared as a set of IDs.
+    /// A difference in any other field is reported as a change to the account itself.
+    pub(crate) fn diff_into(&self, other: &Account, path: &str, diffs: &mut Vec<DiffEntry>) {
+        if self.name != other.name
+            || self.birthday_height != other.birthday_height
+            || self.birthday_block != other.birthday_block
+            || self.zip32_account_id != other.zip32_account_id
+            || self.sapling_sent_outputs != other.sapling_sent_outputs
+            || self.orchard_sent_outputs != other.orchard_sent_outputs
+            || self.attachments != other.attachments
+        {
+            diffs.push(DiffEntry::new(path, DiffKind::Changed));
+        }
+
+        // An account may hold the same address more than once, so each address string maps to
+        // all of its copies in index order.
+        let by_string = |account: &Account| -> BTreeMap<String, Vec<Address>> {
+            let mut map: BTreeMap<String, Vec<Address>> = BTreeMap::new();
+            for address in &account.addresses {
+                let mut address = address.clone();
+                address.set_index(0);
+                map.entry(address.as_string()).or_default().push(address);
+            }
+            map
+        };
+        diff_keyed(
+            path,
+            "addresses",
+            by_string(self),
+            by_string(other),
+            diffs,
+            |item, ours, theirs, diffs| {
+                let shared = ours.len().min(theirs.len());
+                if ours[..shared] != theirs[..shared] {
+                    diffs.push(DiffEntry::new(item, DiffKind::Changed));
+                }
+                for _ in shared..ours.len() {
+                    diffs.push(DiffEntry::new(item, DiffKind::Removed));
+                }
+                for _ in shared..theirs.len() {
+                    diffs.push(DiffEntry::new(item, DiffKind::Added));
+                }
+            },
+        );
+
+        let txids = |account: &Account| -> BTreeMap<TxId, ()> {
+            account
+                .relevant_transactions
+                .iter()
+                .map(|txid| (*txid, ()))
+                .collect()
+        };
+        diff_keyed(
+            path,
+            "relevant_transactions",
+            txids(self),
+            txids(other),
+            diffs,
+            |_, _, _, _| {},
+        );
+    }
+}
+
+/// Looks up an element by its stored index, trying the matching vector position first.
+fn find_indexed<T: Indexed>(items: &[T], index: usize) -> Option<&T> {
+    items
+        .get(index)
+        .filter(|item| item.index() == index)
+        .or_else(|| items.iter().find(|item| item.index() == index))
 }
 
 impl Default for Account {
@@ -235,15 +549,15 @@ impl From<Account> for Envelope {
     fn from(value: Account) -> Self {
         let mut e = Envelope::new(value.index)
             .add_type("Account")
-            .add_assertion("name", value.name)
-            .add_optional_assertion("birthday_height", value.birthday_height)
-            .add_optional_assertion("birthday_block", value.birthday_block)
-            .add_optional_assertion("zip32_account_id", value.zip32_account_id)
-            .add_assertion("relevant_transactions", value.relevant_transactions.sort_by_cbor_encoding()); // Deterministic ordering
+            .add_assertion(NAME.known_value(), value.name)
+            .add_optional_assertion(BIRTHDAY_HEIGHT.known_value(), value.birthday_height)
+            .add_optional_assertion(BIRTHDAY_BLOCK.known_value(), value.birthday_block)
+            .add_optional_assertion(ZIP32_ACCOUNT_ID.known_value(), value.zip32_account_id)
+            .add_assertion(RELEVANT_TRANSACTIONS.known_value(), value.relevant_transactions.sort_by_cbor_encoding()); // Deterministic ordering
 
-        e = value.addresses.iter().fold(e, |e, address| e.add_assertion("address", address.clone()));
-        e = value.sapling_sent_outputs.iter().fold(e, |e, output| e.add_assertion("sapling_sent_output", output.clone()));
-        e = value.orchard_sent_outputs.iter().fold(e, |e, output| e.add_assertion("orchard_sent_output", output.clone()));
+        e = value.addresses.iter().fold(e, |e, address| e.add_assertion(ADDRESS.known_value(), address.clone()));
+        e = value.sapling_sent_outputs.iter().fold(e, |e, output| e.add_assertion(SAPLING_SENT_OUTPUT.known_value(), output.clone()));
+        e = value.orchard_sent_outputs.iter().fold(e, |e, output| e.add_assertion(ORCHARD_SENT_OUTPUT.known_value(), output.clone()));
 
         value.attachments.add_to_envelope(e)
     }
@@ -255,30 +569,29 @@ impl TryFrom<Envelope> for Account {
     fn try_from(envelope: Envelope) -> Result<Self> {
         envelope.check_type_envelope("Account").context("account")?;
         let index = envelope.extract_subject().context("index")?;
-        let name = envelope
-            .extract_object_for_predicate("name")
-            .context("name")?;
-        let birthday_height = envelope
-            .extract_optional_object_for_predicate("birthday_height")
+        let name = NAME.extract_object(&envelope).context("name")?;
+        let birthday_height = BIRTHDAY_HEIGHT
+            .extract_optional_object(&envelope)
             .context("birthday_height")?;
-        let birthday_block = envelope
-            .extract_optional_object_for_predicate("birthday_block")
+        let birthday_block = BIRTHDAY_BLOCK
+            .extract_optional_object(&envelope)
             .context("birthday_block")?;
-        let zip32_account_id = envelope
-            .extract_optional_object_for_predicate("zip32_account_id")
+        let zip32_account_id = ZIP32_ACCOUNT_ID
+            .extract_optional_object(&envelope)
             .context("zip32_account_id")?;
-        let relevant_transactions = envelope
-            .extract_object_for_predicate("relevant_transactions")
+        let relevant_transactions = RELEVANT_TRANSACTIONS
+            .extract_object(&envelope)
             .context("relevant_transactions")?;
 
-        let addresses =
-            envelope_indexed_objects_for_predicate(&envelope, "address").context("addresses")?;
-        let sapling_sent_outputs =
-            envelope_indexed_objects_for_predicate(&envelope, "sapling_sent_output")
-                .context("sapling_sent_outputs")?;
-        let orchard_sent_outputs =
-            envelope_indexed_objects_for_predicate(&envelope, "orchard_sent_output")
-                .context("orchard_sent_outputs")?;
+        let addresses = ADDRESS
+            .try_indexed_objects(&envelope)
+            .context("addresses")?;
+        let sapling_sent_outputs = SAPLING_SENT_OUTPUT
+            .try_indexed_objects(&envelope)
+            .context("sapling_sent_outputs")?;
+        let orchard_sent_outputs = ORCHARD_SENT_OUTPUT
+            .try_indexed_objects(&envelope)
+            .context("orchard_sent_outputs")?;
 
         let attachments = Attachments::try_from_envelope(&envelope).context("attachments")?;
 
@@ -297,34 +610,309 @@ impl TryFrom<Envelope> for Account {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for Account {
+    fn random() -> Self {
+        use crate::SetIndexes;
+
+        Self {
+            index: 0,
+            name: String::random(),
+            birthday_height: BlockHeight::opt_random(),
+            birthday_block: BlockHash::opt_random(),
+            zip32_account_id: u32::opt_random(),
+            addresses: Vec::random().set_indexes(),
+            relevant_transactions: HashSet::random(),
+            sapling_sent_outputs: Vec::random().set_indexes(),
+            orchard_sent_outputs: Vec::random().set_indexes(),
+            attachments: Attachments::random(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::collections::HashSet;
+    use bc_envelope::prelude::*;
+
+    use crate::{
+        Address, BlockHash, BlockHeight, DerivationInfo, Indexed, ProtocolAddress, RandomInstance,
+        TxId, UnifiedAddress, orchard::OrchardSentOutput, predicate::NAME, sapling,
+        sapling::SaplingSentOutput, test_envelope_roundtrip, transparent,
+    };
 
-    use bc_envelope::Attachments;
+    use super::{Account, BIRTHDAY_VERIFICATION_WINDOW};
 
-    use crate::{BlockHash, BlockHeight, test_envelope_roundtrip};
+    test_envelope_roundtrip!(Account);
 
-    use super::Account;
+    fn address(s: &str) -> Address {
+        Address::new(ProtocolAddress::Transparent(transparent::Address::new(s)))
+    }
 
-    impl crate::RandomInstance for Account {
-        fn random() -> Self {
-            use crate::SetIndexes;
+    #[test]
+    fn test_requires_birthday_verification() {
+        let export_height = BlockHeight::from_u32(2_000_000);
+        let mut account = Account::new();
+        assert!(!account.requires_birthday_verification(export_height));
 
-            Self {
-                index: 0,
-                name: String::random(),
-                birthday_height: BlockHeight::opt_random(),
-                birthday_block: BlockHash::opt_random(),
-                zip32_account_id: u32::opt_random(),
-                addresses: Vec::random().set_indexes(),
-                relevant_transactions: HashSet::random(),
-                sapling_sent_outputs: Vec::random().set_indexes(),
-                orchard_sent_outputs: Vec::random().set_indexes(),
-                attachments: Attachments::random(),
-            }
+        account.set_birthday_height(Some(export_height - BIRTHDAY_VERIFICATION_WINDOW));
+        assert!(account.requires_birthday_verification(export_height));
+
+        account.set_birthday_height(Some(export_height - (BIRTHDAY_VERIFICATION_WINDOW + 1)));
+        assert!(!account.requires_birthday_verification(export_height));
+    }
+
+    #[test]
+    fn test_birthday_checkpoint() {
+        let height = BlockHeight::from_u32(1_000_000);
+        let hash = BlockHash::from_bytes([7u8; 32]);
+        let mut account = Account::new();
+        assert_eq!(account.birthday_checkpoint(), None);
+
+        account.set_birthday_height(Some(height));
+        assert_eq!(account.birthday_checkpoint(), None);
+
+        account.set_birthday_block(Some(hash));
+        let checkpoint = account.birthday_checkpoint().unwrap();
+        assert_eq!(checkpoint.height(), height);
+        assert_eq!(checkpoint.hash(), hash);
+
+        account.set_birthday_height(None);
+        assert_eq!(account.birthday_checkpoint(), None);
+    }
+
+    #[test]
+    fn test_birthday_consistency() {
+        let height = BlockHeight::from_u32(1_000_000);
+        let block = BlockHash::from_bytes([7u8; 32]);
+
+        let mut account = Account::new();
+        assert!(account.has_consistent_birthday());
+        assert!(account.validate_birthday().is_ok());
+
+        account.set_birthday_height(Some(height));
+        assert!(!account.has_consistent_birthday());
+        let error = account.validate_birthday().unwrap_err();
+        assert!(error.to_string().contains("no birthday block"));
+
+        account.set_birthday_block(Some(block));
+        assert!(account.has_consistent_birthday());
+        assert!(account.validate_birthday().is_ok());
+
+        account.set_birthday_height(None);
+        assert!(!account.has_consistent_birthday());
+        let error = account.validate_birthday().unwrap_err();
+        assert!(error.to_string().contains("no birthday height"));
+    }
+
+    #[test]
+    fn test_addresses_by_kind() {
+        let mut account = Account::new();
+        account.add_address(address("t1a"));
+        account.add_address(Address::new(ProtocolAddress::Sapling(Box::new(
+            sapling::Address::new("zs1a".to_string()),
+        ))));
+        account.add_address(address("t1b"));
+        account.add_address(Address::new(ProtocolAddress::Unified(Box::new(
+            UnifiedAddress::new("u1a".to_string()),
+        ))));
+
+        let strings = |addresses: Vec<&Address>| -> Vec<String> {
+            addresses.iter().map(|a| a.as_string()).collect()
+        };
+        assert_eq!(
+            strings(account.transparent_addresses().collect()),
+            vec!["t1a", "t1b"]
+        );
+        assert_eq!(
+            strings(account.shielded_addresses().collect()),
+            vec!["zs1a"]
+        );
+        assert_eq!(strings(account.unified_addresses().collect()), vec!["u1a"]);
+
+        assert_eq!(Account::new().transparent_addresses().count(), 0);
+    }
+
+    #[test]
+    fn test_used_nonhardened_indices() {
+        let mut account = Account::new();
+
+        let mut t = transparent::Address::new("t1a");
+        t.set_derivation_info(DerivationInfo::new(0u32.into(), 4u32.into()));
+        account.add_address(Address::new(ProtocolAddress::Transparent(t)));
+
+        let mut change = transparent::Address::new("t1b");
+        change.set_derivation_info(DerivationInfo::new(1u32.into(), 0u32.into()));
+        account.add_address(Address::new(ProtocolAddress::Transparent(change)));
+
+        let mut s = sapling::Address::new("zs1a".to_string());
+        s.set_hd_derivation_path("m/32'/133'/0'/0/4".to_string());
+        account.add_address(Address::new(ProtocolAddress::Sapling(Box::new(s))));
+
+        let mut u = UnifiedAddress::new("u1a".to_string());
+        u.set_hd_derivation_path("m/32'/133'/0'/0/2".to_string());
+        account.add_address(Address::new(ProtocolAddress::Unified(Box::new(u))));
+
+        let mut hardened = UnifiedAddress::new("u1b".to_string());
+        hardened.set_hd_derivation_path("m/32'/133'/0'".to_string());
+        account.add_address(Address::new(ProtocolAddress::Unified(Box::new(hardened))));
+        account.add_address(address("t1c"));
+
+        let indices: Vec<u32> = account
+            .used_nonhardened_indices()
+            .into_iter()
+            .map(u32::from)
+            .collect();
+        assert_eq!(indices, vec![0, 2, 4]);
+        assert!(Account::new().used_nonhardened_indices().is_empty());
+    }
+
+    #[test]
+    fn test_canonicalize_digest_independent_of_insertion_order() {
+        let outputs: Vec<SaplingSentOutput> = (0..3).map(|_| SaplingSentOutput::random()).collect();
+
+        let mut a = Account::new();
+        let mut b = Account::new();
+        for s in ["t1a", "t1b", "t1c"] {
+            a.add_address(address(s));
+        }
+        for s in ["t1c", "t1a", "t1b"] {
+            b.add_address(address(s));
+        }
+        for output in &outputs {
+            a.add_sapling_sent_output(output.clone());
         }
+        for output in outputs.iter().rev() {
+            b.add_sapling_sent_output(output.clone());
+        }
+
+        assert_ne!(
+            Envelope::from(a.clone()).digest(),
+            Envelope::from(b.clone()).digest()
+        );
+
+        a.canonicalize();
+        b.canonicalize();
+        assert_eq!(a, b);
+        assert!(
+            a.addresses()
+                .iter()
+                .enumerate()
+                .all(|(i, a)| a.index() == i)
+        );
+        assert_eq!(Envelope::from(a).digest(), Envelope::from(b).digest());
     }
 
-    test_envelope_roundtrip!(Account);
+    #[test]
+    fn test_legacy_string_predicates() {
+        let mut account = Account::new();
+        account.set_name("Savings");
+        account.set_zip32_account_id(2);
+        account.add_address(address("t1a"));
+        account.add_address(address("t1b"));
+
+        let compact = Envelope::from(account.clone());
+        assert!(compact.objects_for_predicate("name").is_empty());
+        assert_eq!(Account::try_from(compact).unwrap(), account);
+
+        // The same account as written by format version 1, with string predicates.
+        let legacy = account.addresses().iter().rev().fold(
+            Envelope::new(0usize)
+                .add_type("Account")
+                .add_assertion("name", "Savings")
+                .add_assertion("zip32_account_id", 2u32)
+                .add_assertion("relevant_transactions", Vec::<CBOR>::new()),
+            |e, address| e.add_assertion("address", address.clone()),
+        );
+        assert_eq!(Account::try_from(legacy.clone()).unwrap(), account);
+
+        let mixed = legacy.add_assertion(NAME.known_value(), "Other");
+        assert!(Account::try_from(mixed).is_err());
+    }
+
+    #[test]
+    fn test_sent_output_by_index() {
+        let mut account = Account::new();
+        let sapling = vec![SaplingSentOutput::random(), SaplingSentOutput::random()];
+        for output in sapling.iter().cloned() {
+            account.add_sapling_sent_output(output);
+        }
+        let orchard = OrchardSentOutput::random();
+        account.add_orchard_sent_output(orchard.clone());
+
+        let second = account.sapling_sent_output(1).unwrap();
+        assert_eq!(second.index(), 1);
+        assert_eq!(second.recipient_address(), sapling[1].recipient_address());
+        assert!(account.sapling_sent_output(2).is_none());
+        assert_eq!(
+            account.orchard_sent_output(0).unwrap().recipient_address(),
+            orchard.recipient_address()
+        );
+        assert!(account.orchard_sent_output(1).is_none());
+    }
+
+    #[test]
+    fn test_merge_disjoint_accounts() {
+        let mut a = Account::new();
+        a.set_birthday_height(Some(BlockHeight::from_u32(2000)));
+        a.add_address(address("t1a"));
+        a.add_address(address("t1shared"));
+        a.add_relevant_transaction(TxId::from_bytes([1u8; 32]));
+        a.add_sapling_sent_output(SaplingSentOutput::random());
+
+        let mut b = Account::new();
+        b.set_zip32_account_id(3);
+        b.set_birthday_height(Some(BlockHeight::from_u32(1000)));
+        b.set_birthday_block(Some(BlockHash::from_bytes([7u8; 32])));
+        b.add_address(address("t1shared"));
+        b.add_address(address("t1b"));
+        b.add_relevant_transaction(TxId::from_bytes([1u8; 32]));
+        b.add_relevant_transaction(TxId::from_bytes([2u8; 32]));
+        b.add_sapling_sent_output(SaplingSentOutput::random());
+
+        a.merge_from(b).unwrap();
+
+        let addresses: Vec<String> = a.addresses().iter().map(|a| a.as_string()).collect();
+        assert_eq!(addresses, vec!["t1a", "t1shared", "t1b"]);
+        assert!(
+            a.addresses()
+                .iter()
+                .enumerate()
+                .all(|(i, a)| a.index() == i)
+        );
+        assert_eq!(a.relevant_transactions_len(), 2);
+        assert_eq!(a.sapling_sent_outputs_len(), 2);
+        assert_eq!(a.sapling_sent_outputs()[1].index(), 1);
+        assert_eq!(a.birthday_height(), Some(BlockHeight::from_u32(1000)));
+        assert_eq!(a.birthday_block(), Some(BlockHash::from_bytes([7u8; 32])));
+        assert_eq!(a.zip32_account_id(), Some(3));
+    }
+
+    #[test]
+    fn test_merge_repeated_address() {
+        let mut a = Account::new();
+        a.add_address(address("t1a"));
+        let mut b = Account::new();
+        b.add_address(address("t1b"));
+        b.add_address(address("t1b"));
+        b.add_address(address("t1a"));
+
+        a.merge_from(b).unwrap();
+
+        let addresses: Vec<String> = a.addresses().iter().map(|a| a.as_string()).collect();
+        assert_eq!(addresses, vec!["t1a", "t1b"]);
+    }
+
+    #[test]
+    fn test_merge_conflicting_account_ids() {
+        let mut a = Account::new();
+        a.set_zip32_account_id(0);
+        a.add_address(address("t1a"));
+        let mut b = Account::new();
+        b.set_zip32_account_id(1);
+        b.add_address(address("t1b"));
+
+        let before = a.clone();
+        assert!(a.merge_from(b).is_err());
+        assert_eq!(a, before);
+    }
 }
@@ -325,25 +325,24 @@ impl TryFrom<Envelope> for Address {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for Address {
+    fn random() -> Self {
+        Self {
+            index: 0,
+            name: String::random(),
+            purpose: String::opt_random(),
+            address: ProtocolAddress::random(),
+            attachments: Attachments::random(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use bc_envelope::Attachments;
-
-    use crate::{ProtocolAddress, test_envelope_roundtrip};
+    use crate::test_envelope_roundtrip;
 
     use super::Address;
 
-    impl crate::RandomInstance for Address {
-        fn random() -> Self {
-            Self {
-                index: 0,
-                name: String::random(),
-                purpose: String::opt_random(),
-                address: ProtocolAddress::random(),
-                attachments: Attachments::random(),
-            }
-        }
-    }
-
     test_envelope_roundtrip!(Address);
 }
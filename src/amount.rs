@@ -205,6 +205,72 @@ impl Amount {
         }
         Some(result)
     }
+
+    /// Adds two Amounts, clamping the result to `{-MAX_BALANCE..MAX_BALANCE}`.
+    ///
+    /// Clamping silently discards any value beyond the protocol bounds, so this should only
+    /// be used where an approximate result is acceptable. Use `+` when overflow must be
+    /// detected.
+    pub fn saturating_add(self, rhs: Amount) -> Amount {
+        Amount((self.0 + rhs.0).clamp(-MAX_BALANCE, MAX_BALANCE))
+    }
+
+    /// Subtracts one Amount from another, clamping the result to `{-MAX_BALANCE..MAX_BALANCE}`.
+    ///
+    /// Clamping silently discards any value beyond the protocol bounds, so this should only
+    /// be used where an approximate result is acceptable. Use `-` when overflow must be
+    /// detected.
+    pub fn saturating_sub(self, rhs: Amount) -> Amount {
+        Amount((self.0 - rhs.0).clamp(-MAX_BALANCE, MAX_BALANCE))
+    }
+
+    /// Multiplies this Amount by a rate expressed in basis points (hundredths of a percent),
+    /// i.e. by `bps / 10_000`.
+    ///
+    /// The multiplication is performed before the division so that no precision is lost to
+    /// intermediate rounding; the final result is rounded toward zero. Returns `None` if the
+    /// result is outside `-MAX_BALANCE..=MAX_BALANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::Amount;
+    /// let one_zec = Amount::const_from_u64(100_000_000);
+    /// // 25 bps = 0.25%
+    /// assert_eq!(one_zec.mul_bps(25), Some(Amount::const_from_u64(250_000)));
+    /// ```
+    pub fn mul_bps(self, bps: u32) -> Option<Amount> {
+        // An i128 holds the product of any i64 and u32, so only the final range needs checking.
+        let result = i128::from(self.0) * i128::from(bps) / 10_000;
+        if !(-i128::from(MAX_BALANCE)..=i128::from(MAX_BALANCE)).contains(&result) {
+            return None;
+        }
+        Some(Amount(result as i64))
+    }
+
+    /// Formats this Amount for display to people, as ZEC with the integer part grouped into
+    /// thousands by `separator` and all 8 fractional digits kept.
+    ///
+    /// This is intended for reports; the `Debug` representation is unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::Amount;
+    /// let amount = Amount::const_from_u64(123_456_780_000);
+    /// assert_eq!(amount.to_formatted_string(','), "1,234.56780000 ZEC");
+    /// ```
+    pub fn to_formatted_string(&self, separator: char) -> String {
+        let zats = self.0.unsigned_abs();
+        let integer = (zats / COIN).to_string();
+        let mut grouped = String::new();
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+        let sign = if self.is_negative() { "-" } else { "" };
+        format!("{}{}.{:08} ZEC", sign, grouped, zats % COIN)
+    }
 }
 
 /// Converts an i64 into an Amount, with range checking
@@ -348,20 +414,92 @@ impl TryFrom<Envelope> for Amount {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for Amount {
+    fn random() -> Self {
+        let mut rng = bc_rand::thread_rng();
+        let value = rand::Rng::gen_range(&mut rng, -MAX_BALANCE..=MAX_BALANCE);
+        Self(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{test_cbor_roundtrip, test_envelope_roundtrip};
 
     use super::{Amount, MAX_BALANCE};
 
-    impl crate::RandomInstance for Amount {
-        fn random() -> Self {
-            let mut rng = bc_rand::thread_rng();
-            let value = rand::Rng::gen_range(&mut rng, -MAX_BALANCE..=MAX_BALANCE);
-            Self(value)
-        }
-    }
-
     test_cbor_roundtrip!(Amount);
     test_envelope_roundtrip!(Amount);
+
+    #[test]
+    fn test_saturating_add() {
+        let a = Amount::from_i64(100).unwrap();
+        let b = Amount::from_i64(-30).unwrap();
+        assert_eq!(a.saturating_add(b), Amount::from_i64(70).unwrap());
+
+        let max = Amount::const_from_i64(MAX_BALANCE);
+        assert_eq!(max.saturating_add(a), max);
+        let min = Amount::const_from_i64(-MAX_BALANCE);
+        assert_eq!(min.saturating_add(b), min);
+    }
+
+    #[test]
+    fn test_saturating_sub() {
+        let a = Amount::from_i64(100).unwrap();
+        assert_eq!(a.saturating_sub(a), Amount::zero());
+
+        let max = Amount::const_from_i64(MAX_BALANCE);
+        let min = Amount::const_from_i64(-MAX_BALANCE);
+        assert_eq!(max.saturating_sub(min), max);
+        assert_eq!(min.saturating_sub(max), min);
+    }
+
+    #[test]
+    fn test_mul_bps() {
+        let one_zec = Amount::from_u64(100_000_000).unwrap();
+        assert_eq!(
+            one_zec.mul_bps(25),
+            Some(Amount::from_u64(250_000).unwrap())
+        );
+        assert_eq!(one_zec.mul_bps(10_000), Some(one_zec));
+        assert_eq!(one_zec.mul_bps(0), Some(Amount::zero()));
+
+        // 1 bp of 9_999 zats is 0.9999 zats, which rounds toward zero.
+        assert_eq!(
+            Amount::from_u64(9_999).unwrap().mul_bps(1),
+            Some(Amount::zero())
+        );
+    }
+
+    #[test]
+    fn test_to_formatted_string() {
+        let large = Amount::const_from_u64(1_234_567_800_000_001);
+        assert_eq!(large.to_formatted_string(','), "12,345,678.00000001 ZEC");
+        assert_eq!(large.to_formatted_string('.'), "12.345.678.00000001 ZEC");
+        assert_eq!(
+            Amount::const_from_u64(100_000_000_000).to_formatted_string(','),
+            "1,000.00000000 ZEC"
+        );
+
+        let small = Amount::const_from_u64(5_000_000);
+        assert_eq!(small.to_formatted_string(','), "0.05000000 ZEC");
+        assert_eq!((-small).to_formatted_string(','), "-0.05000000 ZEC");
+    }
+
+    #[test]
+    fn test_mul_bps_overflow() {
+        let max = Amount::const_from_i64(MAX_BALANCE);
+        assert_eq!(max.mul_bps(u32::MAX), None);
+        assert_eq!(max.mul_bps(20_000), None);
+        assert_eq!(max.mul_bps(10_000), Some(max));
+
+        let min = Amount::const_from_i64(-MAX_BALANCE);
+        assert_eq!(min.mul_bps(10_000), Some(min));
+        assert_eq!(min.mul_bps(20_000), None);
+        assert_eq!(
+            Amount::from_i64(-100_000_000).unwrap().mul_bps(25),
+            Some(Amount::from_i64(-250_000).unwrap())
+        );
+    }
 }
@@ -35,3 +35,28 @@ let anchor = Anchor::new([0u8; 32]);
 impl Copy for Anchor {}
 
 blob_envelope!(Anchor);
+
+#[cfg(test)]
+mod tests {
+    use super::Anchor;
+
+    #[test]
+    fn test_reversed_hex() {
+        let mut bytes = [0u8; 32];
+        bytes[0] = 0x01;
+        bytes[31] = 0xff;
+        let anchor = Anchor::new(bytes);
+        assert_eq!(
+            anchor.to_hex(),
+            "01000000000000000000000000000000000000000000000000000000000000ff"
+        );
+        assert_eq!(
+            anchor.to_reversed_hex(),
+            "ff00000000000000000000000000000000000000000000000000000000000001"
+        );
+        assert_eq!(
+            Anchor::from_reversed_hex(&anchor.to_reversed_hex()).unwrap(),
+            anchor
+        );
+    }
+}
@@ -0,0 +1,34 @@
+/// Counts of the attachments carried by a container, produced by
+/// [`Zewif::attachment_stats`](crate::Zewif::attachment_stats).
+///
+/// Each object's [`Attachments`](bc_envelope::prelude::Attachments) are keyed by digest, so a
+/// single object never holds the same attachment twice. The same attachment can still be
+/// carried by several objects, and is then serialized once per object; the difference
+/// between [`total`](Self::total) and [`unique`](Self::unique) shows how much of that
+/// repetition a container has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttachmentStats {
+    total: usize,
+    unique: usize,
+}
+
+impl AttachmentStats {
+    pub(crate) fn new(total: usize, unique: usize) -> Self {
+        Self { total, unique }
+    }
+
+    /// The number of attachments across all objects in the container.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// The number of distinct attachment digests across all objects in the container.
+    pub fn unique(&self) -> usize {
+        self.unique
+    }
+
+    /// The number of attachments that repeat one carried by another object.
+    pub fn duplicates(&self) -> usize {
+        self.total - self.unique
+    }
+}
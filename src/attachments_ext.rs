@@ -0,0 +1,128 @@
+use bc_envelope::prelude::*;
+
+/// Enumeration helpers for [`Attachments`].
+///
+/// `Attachments` is defined in `bc-envelope` and exposes lookup only by digest, so this trait
+/// provides a way to list what a ZeWIF object carries, e.g. for migration reports.
+///
+/// The digests are recovered by laying the attachments out on a placeholder envelope, so
+/// each call is linear in the number of attachments.
+///
+/// # Examples
+/// ```
+/// # use bc_envelope::prelude::*;
+/// # use zewif::AttachmentsExt;
+/// let mut attachments = Attachments::new();
+/// attachments.add("payload", "com.example", None::<&str>);
+/// assert_eq!(attachments.len(), 1);
+/// for (digest, envelope) in attachments.iter() {
+///     assert_eq!(envelope.digest().into_owned(), digest);
+/// }
+/// ```
+pub trait AttachmentsExt {
+    /// Returns the number of attachments.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no attachments.
+    fn is_empty(&self) -> bool;
+
+    /// Iterates over each attachment envelope together with its digest.
+    fn iter(&self) -> impl Iterator<Item = (Digest, &Envelope)>;
+
+    /// Returns the attachments whose `vendor` metadata equals `vendor`.
+    fn find_by_vendor(&self, vendor: &str) -> Vec<&Envelope> {
+        self.iter()
+            .map(|(_, envelope)| envelope)
+            .filter(|envelope| envelope.attachment_vendor().is_ok_and(|v| v == vendor))
+            .collect()
+    }
+
+    /// Returns the attachments whose `conformsTo` metadata equals `conforms_to`.
+    ///
+    /// Attachments without `conformsTo` metadata never match.
+    fn find_by_conformance(&self, conforms_to: &str) -> Vec<&Envelope> {
+        self.iter()
+            .map(|(_, envelope)| envelope)
+            .filter(|envelope| {
+                envelope
+                    .attachment_conforms_to()
+                    .is_ok_and(|c| c.as_deref() == Some(conforms_to))
+            })
+            .collect()
+    }
+}
+
+impl AttachmentsExt for Attachments {
+    fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    fn is_empty(&self) -> bool {
+        Attachments::is_empty(self)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (Digest, &Envelope)> {
+        // Every stored attachment was validated on insertion, so listing them cannot fail.
+        let listed = self
+            .add_to_envelope(Envelope::null())
+            .attachments()
+            .unwrap_or_default();
+        listed.into_iter().filter_map(|attachment| {
+            let digest = attachment.digest().into_owned();
+            self.get(&digest).map(|envelope| (digest, envelope))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use bc_envelope::prelude::*;
+
+    use super::AttachmentsExt;
+
+    #[test]
+    fn test_len_and_iter() {
+        let mut attachments = Attachments::new();
+        assert_eq!(attachments.len(), 0);
+        assert_eq!(attachments.iter().count(), 0);
+
+        attachments.add("one", "com.example", None::<&str>);
+        attachments.add("two", "com.example", Some("https://example.com/v1"));
+        attachments.add(3, "org.other", None::<&str>);
+        assert_eq!(attachments.len(), 3);
+
+        let mut payloads = HashSet::new();
+        for (digest, envelope) in attachments.iter() {
+            assert_eq!(attachments.get(&digest), Some(envelope));
+            payloads.insert(envelope.attachment_payload().unwrap().digest().into_owned());
+        }
+        assert_eq!(payloads.len(), 3);
+    }
+
+    #[test]
+    fn test_find_by_vendor_and_conformance() {
+        let mut attachments = Attachments::new();
+        attachments.add("one", "com.example", Some("https://example.com/v1"));
+        attachments.add("two", "com.example", None::<&str>);
+        attachments.add("three", "org.other", Some("https://example.com/v1"));
+        attachments.add("four", "org.other", Some("https://other.org/v2"));
+
+        let example = attachments.find_by_vendor("com.example");
+        assert_eq!(example.len(), 2);
+        assert!(
+            example
+                .iter()
+                .all(|e| e.attachment_vendor().unwrap() == "com.example")
+        );
+        assert_eq!(attachments.find_by_vendor("org.other").len(), 2);
+        assert!(attachments.find_by_vendor("net.unknown").is_empty());
+
+        let v1 = attachments.find_by_conformance("https://example.com/v1");
+        assert_eq!(v1.len(), 2);
+        let v2 = attachments.find_by_conformance("https://other.org/v2");
+        assert_eq!(v2.len(), 1);
+        assert_eq!(v2[0].attachment_vendor().unwrap(), "org.other");
+    }
+}
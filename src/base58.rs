@@ -0,0 +1,44 @@
+use anyhow::{Result, bail};
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Decodes a Base58Check string, verifying and stripping the 4-byte checksum.
+///
+/// The returned payload includes any version prefix bytes.
+pub(crate) fn decode_check(encoded: &str) -> Result<Vec<u8>> {
+    let mut bytes = decode(encoded)?;
+    if bytes.len() < 4 {
+        bail!("Base58Check string is too short");
+    }
+    let checksum = bytes.split_off(bytes.len() - 4);
+    if bc_crypto::double_sha256(&bytes)[..4] != checksum[..] {
+        bail!("Base58Check checksum mismatch");
+    }
+    Ok(bytes)
+}
+
+/// Decodes a Base58 string (without checksum verification).
+fn decode(encoded: &str) -> Result<Vec<u8>> {
+    // Big-endian base-256 digits of the decoded value, accumulated one base-58 digit at a time.
+    let mut digits: Vec<u8> = Vec::new();
+    for c in encoded.bytes() {
+        let Some(value) = ALPHABET.iter().position(|&a| a == c) else {
+            bail!("Invalid Base58 character: {:?}", c as char);
+        };
+        let mut carry = value as u32;
+        for digit in digits.iter_mut().rev() {
+            carry += (*digit as u32) * 58;
+            *digit = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            digits.insert(0, (carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+    // Each leading '1' encodes a leading zero byte.
+    let leading_zeros = encoded.bytes().take_while(|&c| c == b'1').count();
+    let mut result = vec![0u8; leading_zeros];
+    result.extend(digits);
+    Ok(result)
+}
@@ -0,0 +1,54 @@
+use anyhow::{Result, bail};
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes bytes as padded base64 with the standard alphabet (RFC 4648 §4).
+pub(crate) fn encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let mut buf = [0u8; 3];
+        buf[..chunk.len()].copy_from_slice(chunk);
+        let group = u32::from_be_bytes([0, buf[0], buf[1], buf[2]]);
+        // A chunk of n bytes produces n + 1 significant characters.
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (group >> (18 - 6 * i)) & 0x3f;
+                encoded.push(ALPHABET[index as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Decodes padded base64 with the standard alphabet (RFC 4648 §4).
+///
+/// Padding is required and whitespace is not accepted.
+pub(crate) fn decode(encoded: &str) -> Result<Vec<u8>> {
+    let bytes = encoded.as_bytes();
+    if bytes.len() % 4 != 0 {
+        bail!(
+            "Base64 string length {} is not a multiple of 4",
+            bytes.len()
+        );
+    }
+    let mut decoded = Vec::with_capacity(bytes.len() / 4 * 3);
+    let groups = bytes.len() / 4;
+    for (n, group) in bytes.chunks(4).enumerate() {
+        let padding = group.iter().rev().take_while(|&&c| c == b'=').count();
+        if padding > 2 || (padding > 0 && n + 1 != groups) {
+            bail!("Invalid Base64 padding");
+        }
+        let mut value = 0u32;
+        for &c in &group[..4 - padding] {
+            let Some(digit) = ALPHABET.iter().position(|&a| a == c) else {
+                bail!("Invalid Base64 character: {:?}", c as char);
+            };
+            value = (value << 6) | digit as u32;
+        }
+        value <<= 6 * padding;
+        decoded.extend_from_slice(&value.to_be_bytes()[1..4 - padding]);
+    }
+    Ok(decoded)
+}
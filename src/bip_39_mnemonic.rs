@@ -1,4 +1,4 @@
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use bc_envelope::prelude::*;
 
 use crate::{MnemonicLanguage, NoQuotesDebugOption, SeedFingerprint};
@@ -55,11 +55,101 @@ impl Bip39Mnemonic {
     pub fn set_language(&mut self, language: MnemonicLanguage) {
         self.language = Some(language);
     }
+
+    /// Encodes raw entropy as a BIP-39 mnemonic in the given language.
+    ///
+    /// The entropy must be 16, 20, 24, 28 or 32 bytes long, producing 12, 15, 18, 21 or 24
+    /// words respectively. The checksum bits are taken from the SHA-256 hash of the entropy.
+    ///
+    /// # Errors
+    /// Returns an error if the entropy length is not supported, or if no wordlist is bundled
+    /// for `language`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::{Bip39Mnemonic, MnemonicLanguage};
+    /// let mnemonic = Bip39Mnemonic::from_entropy(&[0; 16], MnemonicLanguage::English).unwrap();
+    /// assert!(mnemonic.mnemonic().ends_with("abandon about"));
+    /// assert_eq!(mnemonic.to_entropy().unwrap(), vec![0; 16]);
+    /// ```
+    pub fn from_entropy(entropy: &[u8], language: MnemonicLanguage) -> Result<Self> {
+        if !matches!(entropy.len(), 16 | 20 | 24 | 28 | 32) {
+            bail!(
+                "BIP-39 entropy must be 16, 20, 24, 28 or 32 bytes, got {}",
+                entropy.len()
+            );
+        }
+        let checksum_bits = entropy.len() / 4;
+        let checksum = bc_crypto::sha256(entropy)[0];
+        let mut bits: Vec<bool> = entropy
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+            .collect();
+        bits.extend((0..checksum_bits).map(|i| (checksum >> (7 - i)) & 1 == 1));
+
+        let words = bits
+            .chunks(11)
+            .map(|chunk| {
+                let index = chunk
+                    .iter()
+                    .fold(0u16, |index, &bit| (index << 1) | bit as u16);
+                language
+                    .word_at(index)
+                    .with_context(|| format!("No wordlist is available for {}", language))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::new(words.join(" "), Some(language)))
+    }
+
+    /// Decodes the mnemonic back into the entropy it encodes, verifying its checksum.
+    ///
+    /// Words are looked up in the mnemonic's language, or in English if no language is set.
+    ///
+    /// # Errors
+    /// Returns an error if the phrase does not have 12, 15, 18, 21 or 24 words, if a word is
+    /// not in the wordlist, or if the checksum does not match.
+    pub fn to_entropy(&self) -> Result<Vec<u8>> {
+        let language = self.language.unwrap_or(MnemonicLanguage::English);
+        let words: Vec<&str> = self.mnemonic.split_whitespace().collect();
+        if !matches!(words.len(), 12 | 15 | 18 | 21 | 24) {
+            bail!(
+                "BIP-39 mnemonic must have 12, 15, 18, 21 or 24 words, got {}",
+                words.len()
+            );
+        }
+        let mut bits = Vec::with_capacity(words.len() * 11);
+        for word in words {
+            let index = language
+                .index_of_word(word)
+                .with_context(|| format!("Word is not in the {} wordlist", language))?;
+            bits.extend((0..11).rev().map(|i| (index >> i) & 1 == 1));
+        }
+
+        let checksum_bits = bits.len() / 33;
+        let (entropy_bits, checksum) = bits.split_at(bits.len() - checksum_bits);
+        let entropy: Vec<u8> = entropy_bits
+            .chunks(8)
+            .map(|chunk| chunk.iter().fold(0u8, |byte, &bit| (byte << 1) | bit as u8))
+            .collect();
+        let expected = bc_crypto::sha256(&entropy)[0];
+        if (0..checksum_bits).any(|i| checksum[i] != ((expected >> (7 - i)) & 1 == 1)) {
+            bail!("BIP-39 mnemonic checksum mismatch");
+        }
+        Ok(entropy)
+    }
+}
+
+/// The mnemonic phrase is overwritten with zeros when a `Bip39Mnemonic` is dropped.
+impl Drop for Bip39Mnemonic {
+    fn drop(&mut self) {
+        let mut bytes = std::mem::take(&mut self.mnemonic).into_bytes();
+        bc_crypto::memzero(&mut bytes);
+    }
 }
 
 impl From<Bip39Mnemonic> for Envelope {
-    fn from(value: Bip39Mnemonic) -> Self {
-        Envelope::new(value.mnemonic)
+    fn from(mut value: Bip39Mnemonic) -> Self {
+        Envelope::new(std::mem::take(&mut value.mnemonic))
             .add_type("Bip39Mnemonic")
             .add_optional_assertion("language", value.language)
             .add_optional_assertion("fingerprint", value.fingerprint)
@@ -88,21 +178,74 @@ impl TryFrom<Envelope> for Bip39Mnemonic {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for Bip39Mnemonic {
+    fn random() -> Self {
+        Self {
+            mnemonic: String::random(),
+            language: MnemonicLanguage::opt_random(),
+            fingerprint: SeedFingerprint::opt_random(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{MnemonicLanguage, SeedFingerprint, test_envelope_roundtrip};
+    use crate::{MnemonicLanguage, test_envelope_roundtrip};
 
     use super::Bip39Mnemonic;
 
-    impl crate::RandomInstance for Bip39Mnemonic {
-        fn random() -> Self {
-            Self {
-                mnemonic: String::random(),
-                language: MnemonicLanguage::opt_random(),
-                fingerprint: SeedFingerprint::opt_random(),
-            }
+    test_envelope_roundtrip!(Bip39Mnemonic);
+
+    // English vectors from the BIP-39 reference test suite.
+    const VECTORS: &[(&str, &str)] = &[
+        (
+            "00000000000000000000000000000000",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        ),
+        (
+            "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        ),
+        (
+            "9e885d952ad362caeb4efe34a8e91bd2",
+            "ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic",
+        ),
+        (
+            "000000000000000000000000000000000000000000000000",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon agent",
+        ),
+        (
+            "68a79eaca2324873eacc50cb9c6eca8cc68ea5d936f98787c60c7ebc74e6ce7c",
+            "hamster diagram private dutch cause delay private meat slide toddler razor book happy fancy gospel tennis maple dilemma loan word shrug inflict delay length",
+        ),
+    ];
+
+    #[test]
+    fn test_entropy_vectors() {
+        for (entropy_hex, phrase) in VECTORS {
+            let entropy = hex::decode(entropy_hex).unwrap();
+            let mnemonic =
+                Bip39Mnemonic::from_entropy(&entropy, MnemonicLanguage::English).unwrap();
+            assert_eq!(mnemonic.mnemonic(), phrase);
+            assert_eq!(mnemonic.to_entropy().unwrap(), entropy);
         }
     }
 
-    test_envelope_roundtrip!(Bip39Mnemonic);
+    #[test]
+    fn test_entropy_errors() {
+        let english = MnemonicLanguage::English;
+        assert!(Bip39Mnemonic::from_entropy(&[0; 15], english).is_err());
+        assert!(Bip39Mnemonic::from_entropy(&[0; 16], MnemonicLanguage::French).is_err());
+
+        let bad_checksum = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert!(Bip39Mnemonic::new(bad_checksum, None).to_entropy().is_err());
+        assert!(
+            Bip39Mnemonic::new("abandon about", None)
+                .to_entropy()
+                .is_err()
+        );
+        let unknown = "notaword abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert!(Bip39Mnemonic::new(unknown, None).to_entropy().is_err());
+    }
 }
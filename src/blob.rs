@@ -4,6 +4,7 @@ use hex::FromHexError;
 use std::{
     array::TryFromSliceError,
     fmt,
+    io::{self, Write},
     ops::{
         Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
     },
@@ -151,6 +152,9 @@ impl<const N: usize> Blob<N> {
 
     /// Creates a `Blob` from a slice of bytes.
     ///
+    /// This is the recommended way to build a `Blob` from untrusted input. The
+    /// `From<&[u8]>` and `From<Vec<u8>>` conversions panic on a length mismatch.
+    ///
     /// # Errors
     /// Returns an error if the slice's length doesn't match the expected size N.
     ///
@@ -204,6 +208,118 @@ impl<const N: usize> Blob<N> {
             actual: hex.len(),
         })
     }
+
+    /// Encodes the blob as padded base64 with the standard alphabet (RFC 4648).
+    pub fn to_base64(&self) -> String {
+        crate::base64::encode(&self.0)
+    }
+
+    /// Parses a `Blob` from a padded base64 string with the standard alphabet.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not valid padded base64 or does not decode to
+    /// exactly N bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::Blob;
+    /// let blob = Blob::<3>::from_base64("AQID").unwrap();
+    /// assert_eq!(blob.as_slice(), &[1, 2, 3]);
+    /// assert_eq!(blob.to_base64(), "AQID");
+    /// assert!(Blob::<4>::from_base64("AQID").is_err());
+    /// ```
+    pub fn from_base64(base64: &str) -> Result<Self> {
+        let data = crate::base64::decode(base64)?;
+        Self::from_slice(&data).with_context(|| format!("Expected {} bytes, got {}", N, data.len()))
+    }
+
+    /// Concatenates this blob with another, producing a blob of the combined size.
+    ///
+    /// Stable Rust cannot yet express `Blob<{ N + B }>` as a return type, so the output size
+    /// `C` is a separate const parameter that must be spelled out (or inferred) at the call
+    /// site, and is checked against `N + B` at runtime.
+    ///
+    /// # Panics
+    /// Panics if `N + B != C`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::Blob;
+    ///
+    /// let a = Blob::<2>::new([1, 2]);
+    /// let b = Blob::<3>::new([3, 4, 5]);
+    /// let c: Blob<5> = a.concat(b);
+    /// assert_eq!(c.as_slice(), &[1, 2, 3, 4, 5]);
+    /// ```
+    pub fn concat<const B: usize, const C: usize>(self, other: Blob<B>) -> Blob<C> {
+        assert!(
+            N + B == C,
+            "Cannot concatenate Blob<{}> and Blob<{}> into Blob<{}>",
+            N,
+            B,
+            C
+        );
+        let mut result = [0u8; C];
+        result[..N].copy_from_slice(&self.0);
+        result[N..].copy_from_slice(&other.0);
+        Blob(result)
+    }
+
+    /// Writes the raw bytes of this blob to any destination implementing the `Write` trait.
+    ///
+    /// # Errors
+    /// Returns an IO error if writing fails.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::Blob;
+    /// let blob = Blob::<4>::new([1, 2, 3, 4]);
+    /// let mut buffer = Vec::new();
+    /// blob.write(&mut buffer).unwrap();
+    /// assert_eq!(buffer, vec![1, 2, 3, 4]);
+    /// ```
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(&self.0)
+    }
+
+    /// Copies `M` bytes starting at `start` into a new, smaller blob.
+    ///
+    /// # Errors
+    /// Returns an error if `start + M` exceeds `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::Blob;
+    /// let blob = Blob::<4>::new([1, 2, 3, 4]);
+    /// let middle: Blob<2> = blob.subarray(1).unwrap();
+    /// assert_eq!(middle.as_slice(), &[2, 3]);
+    /// assert!(blob.subarray::<2>(3).is_err());
+    /// ```
+    pub fn subarray<const M: usize>(&self, start: usize) -> Result<Blob<M>> {
+        let end = start
+            .checked_add(M)
+            .filter(|&end| end <= N)
+            .with_context(|| {
+                format!(
+                    "Cannot take {} bytes at offset {} from a Blob<{}>",
+                    M, start, N
+                )
+            })?;
+        Ok(Blob(self.0[start..end].try_into()?))
+    }
+
+    /// Returns the byte-wise XOR of this blob and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::Blob;
+    /// let a = Blob::<2>::new([0b1100, 0xff]);
+    /// let b = Blob::<2>::new([0b1010, 0x0f]);
+    /// assert_eq!(a.xor(&b).as_bytes(), &[0b0110, 0xf0]);
+    /// ```
+    pub fn xor(&self, other: &Blob<N>) -> Blob<N> {
+        Blob(std::array::from_fn(|i| self.0[i] ^ other.0[i]))
+    }
 }
 
 impl<const N: usize> Default for Blob<N> {
@@ -310,12 +426,23 @@ impl<const N: usize> From<&Blob<N>> for Vec<u8> {
     }
 }
 
+/// Converts a vector into a `Blob`.
+///
+/// # Panics
+/// Panics if the vector's length is not `N`. Prefer [`Blob::from_vec`] for data whose length
+/// has not already been checked.
 impl<const N: usize> From<Vec<u8>> for Blob<N> {
     fn from(data: Vec<u8>) -> Self {
         Self::from_vec(data).unwrap()
     }
 }
 
+/// Converts a slice into a `Blob`.
+///
+/// # Panics
+/// Panics if the slice's length is not `N`. Prefer [`Blob::from_slice`] for data whose length
+/// has not already been checked. (A fallible `TryFrom<&[u8]>` cannot be offered alongside this
+/// impl, because the standard library derives `TryFrom` from every `From` impl.)
 impl<const N: usize> From<&[u8]> for Blob<N> {
     fn from(data: &[u8]) -> Self {
         Self::from_vec(data.to_vec()).unwrap()
@@ -376,19 +503,97 @@ impl<const N: usize> TryFrom<Envelope> for Blob<N> {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl<const N: usize> crate::RandomInstance for Blob<N> {
+    fn random() -> Self {
+        let mut rng = bc_rand::thread_rng();
+        Self(bc_rand::rng_random_array(&mut rng))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{test_cbor_roundtrip, test_envelope_roundtrip};
+    use crate::{RandomInstance, test_cbor_roundtrip, test_envelope_roundtrip};
 
     use super::{Blob, Blob32};
 
-    impl<const N: usize> crate::RandomInstance for Blob<N> {
-        fn random() -> Self {
-            let mut rng = bc_rand::thread_rng();
-            Self(bc_rand::rng_random_array(&mut rng))
-        }
-    }
-
     test_cbor_roundtrip!(Blob32);
     test_envelope_roundtrip!(Blob32);
+
+    #[test]
+    fn test_from_slice_length_checked() {
+        let bytes = [7u8; 33];
+        assert_eq!(
+            Blob32::from_slice(&bytes[..32]).unwrap(),
+            Blob::new([7u8; 32])
+        );
+        assert!(Blob32::from_slice(&bytes).is_err());
+        assert!(Blob32::from_slice(&bytes[..31]).is_err());
+        assert!(Blob32::from_vec(bytes.to_vec()).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_slice_conversion_panics_on_mismatch() {
+        let bytes = [7u8; 31];
+        let _ = Blob32::from(&bytes[..]);
+    }
+
+    #[test]
+    fn test_base64_roundtrip() {
+        let blob = Blob32::random();
+        let encoded = blob.to_base64();
+        assert_eq!(encoded.len(), 44);
+        assert_eq!(Blob32::from_base64(&encoded).unwrap(), blob);
+
+        assert!(Blob32::from_base64(&Blob::<31>::new([1; 31]).to_base64()).is_err());
+        assert!(Blob32::from_base64("not base64").is_err());
+    }
+
+    #[test]
+    fn test_concat() {
+        let a = Blob::<11>::new([0x11; 11]);
+        let b = Blob::<21>::new([0x22; 21]);
+        let c: Blob32 = a.concat(b);
+        assert_eq!(&c[..11], &[0x11; 11]);
+        assert_eq!(&c[11..], &[0x22; 21]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_concat_size_mismatch() {
+        let a = Blob::<11>::new([0x11; 11]);
+        let b = Blob::<21>::new([0x22; 21]);
+        let _: Blob<33> = a.concat(b);
+    }
+
+    #[test]
+    fn test_write() {
+        let blob = Blob32::random();
+        let mut buffer = Vec::new();
+        blob.write(&mut buffer).unwrap();
+        assert_eq!(buffer.len(), 32);
+        assert_eq!(buffer, blob.to_vec());
+    }
+
+    #[test]
+    fn test_xor() {
+        let blob = Blob32::random();
+        assert_eq!(blob.xor(&blob), Blob32::default());
+
+        let complement = Blob::new(blob.as_bytes().map(|b| !b));
+        assert_eq!(blob.xor(&complement), Blob::new([0xff; 32]));
+    }
+
+    #[test]
+    fn test_subarray() {
+        let blob = Blob32::new(std::array::from_fn(|i| i as u8));
+        let head: Blob<20> = blob.subarray(0).unwrap();
+        assert_eq!(head.as_slice(), &blob[..20]);
+        let tail: Blob<20> = blob.subarray(12).unwrap();
+        assert_eq!(tail.as_slice(), &blob[12..]);
+
+        assert!(blob.subarray::<20>(13).is_err());
+        assert!(blob.subarray::<1>(usize::MAX).is_err());
+    }
 }
@@ -66,6 +66,8 @@ macro_rules! blob {
 
             /// Creates an instance from a slice of bytes.
             ///
+            /// Prefer this over the `From<&[u8]>` conversion, which panics on a length mismatch.
+            ///
             /// # Errors
             /// Returns an error if the slice's length doesn't match the expected size ($size).
             pub fn from_slice(data: &[u8]) -> Result<Self, std::array::TryFromSliceError> {
@@ -105,6 +107,17 @@ macro_rules! blob {
             pub fn to_hex(&self) -> String {
                 hex::encode(self.0)
             }
+
+            /// Formats the bytes of this object as a hex string in reversed byte order.
+            ///
+            /// The bytes are stored in little-endian (wire) order; block explorers and node RPCs
+            /// display 256-bit values such as anchors and commitments big-endian, which is what
+            /// this produces. It is the inverse of [`from_reversed_hex`](Self::from_reversed_hex).
+            pub fn to_reversed_hex(&self) -> String {
+                let mut data = self.0;
+                data.reverse();
+                hex::encode(data)
+            }
         }
 
         impl PartialEq for $name {
@@ -191,7 +204,7 @@ macro_rules! blob {
             }
         }
 
-        #[cfg(test)]
+        #[cfg(any(test, feature = "test-dependencies"))]
         impl $crate::RandomInstance for $name {
             fn random() -> Self {
                 let mut rng = bc_rand::thread_rng();
@@ -214,19 +214,20 @@ impl TryFrom<Envelope> for BlockHash {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for BlockHash {
+    fn random() -> Self {
+        let mut rng = bc_rand::thread_rng();
+        Self(bc_rand::rng_random_array(&mut rng))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{test_cbor_roundtrip, test_envelope_roundtrip};
 
     use super::BlockHash;
 
-    impl crate::RandomInstance for BlockHash {
-        fn random() -> Self {
-            let mut rng = bc_rand::thread_rng();
-            Self(bc_rand::rng_random_array(&mut rng))
-        }
-    }
-
     test_cbor_roundtrip!(BlockHash);
     test_envelope_roundtrip!(BlockHash);
 }
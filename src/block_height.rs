@@ -3,6 +3,8 @@ use std::cmp::{Ord, Ordering};
 use std::fmt;
 use std::ops::{Add, Sub};
 
+use crate::{Network, NetworkUpgrade};
+
 /// A block's position in the blockchain, represented as a distance from the genesis block.
 ///
 /// `BlockHeight` represents the number of blocks between a specific block and the genesis
@@ -87,6 +89,23 @@ impl BlockHeight {
     pub fn saturating_sub(self, v: u32) -> BlockHeight {
         BlockHeight(self.0.saturating_sub(v))
     }
+
+    /// Returns `true` if `upgrade` had activated on `network` at this height.
+    ///
+    /// Always returns `false` on networks without fixed activation heights (regtest).
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::{BlockHeight, Network, NetworkUpgrade};
+    /// let height = BlockHeight::from_u32(419_200);
+    /// assert!(height.is_active(Network::Main, NetworkUpgrade::Sapling));
+    /// assert!(!height.saturating_sub(1).is_active(Network::Main, NetworkUpgrade::Sapling));
+    /// ```
+    pub fn is_active(&self, network: Network, upgrade: NetworkUpgrade) -> bool {
+        network
+            .activation_height(upgrade)
+            .is_some_and(|activation| *self >= activation)
+    }
 }
 
 /// Displays the block height as a plain number
@@ -226,20 +245,32 @@ impl TryFrom<Envelope> for BlockHeight {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for BlockHeight {
+    fn random() -> Self {
+        let mut rng = bc_rand::thread_rng();
+        let value = rand::Rng::gen_range(&mut rng, 0..u32::MAX);
+        Self(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{test_cbor_roundtrip, test_envelope_roundtrip};
+    use crate::{Network, NetworkUpgrade, test_cbor_roundtrip, test_envelope_roundtrip};
 
     use super::BlockHeight;
 
-    impl crate::RandomInstance for BlockHeight {
-        fn random() -> Self {
-            let mut rng = bc_rand::thread_rng();
-            let value = rand::Rng::gen_range(&mut rng, 0..u32::MAX);
-            Self(value)
-        }
-    }
-
     test_cbor_roundtrip!(BlockHeight);
     test_envelope_roundtrip!(BlockHeight);
+
+    #[test]
+    fn test_is_active() {
+        let sapling = NetworkUpgrade::Sapling;
+        assert!(BlockHeight::from_u32(419_200).is_active(Network::Main, sapling));
+        assert!(!BlockHeight::from_u32(419_199).is_active(Network::Main, sapling));
+        assert!(BlockHeight::from_u32(280_000).is_active(Network::Test, sapling));
+        assert!(!BlockHeight::from_u32(279_999).is_active(Network::Test, sapling));
+        assert!(!BlockHeight::from_u32(419_200).is_active(Network::Main, NetworkUpgrade::Blossom));
+        assert!(!BlockHeight::from_u32(u32::MAX).is_active(Network::Regtest, sapling));
+    }
 }
@@ -0,0 +1,87 @@
+use bc_envelope::{Envelope, prelude::CBOR};
+use dcbor::prelude::*;
+
+use crate::{BlockHash, BlockHeight};
+
+/// A block on the main chain identified by both its height and its hash.
+///
+/// A height alone does not survive a reorg, and a hash alone cannot be located without
+/// scanning, so points used for verification such as an account's birthday carry both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Checkpoint {
+    /// The height of the block.
+    height: BlockHeight,
+    /// The hash of the block at `height`.
+    hash: BlockHash,
+}
+
+impl Checkpoint {
+    pub fn new(height: BlockHeight, hash: BlockHash) -> Self {
+        Self { height, hash }
+    }
+
+    pub fn height(&self) -> BlockHeight {
+        self.height
+    }
+
+    pub fn hash(&self) -> BlockHash {
+        self.hash
+    }
+}
+
+impl From<Checkpoint> for CBOR {
+    fn from(value: Checkpoint) -> Self {
+        let mut map = Map::new();
+        map.insert("height", value.height);
+        map.insert("hash", value.hash);
+        map.into()
+    }
+}
+
+impl TryFrom<CBOR> for Checkpoint {
+    type Error = dcbor::Error;
+
+    fn try_from(value: CBOR) -> dcbor::Result<Self> {
+        if let CBORCase::Map(map) = value.into_case() {
+            let height: BlockHeight = map.extract("height")?;
+            let hash: BlockHash = map.extract("hash")?;
+            Ok(Checkpoint { height, hash })
+        } else {
+            Err("Expected a CBOR map".into())
+        }
+    }
+}
+
+impl From<Checkpoint> for Envelope {
+    fn from(value: Checkpoint) -> Self {
+        Envelope::new(CBOR::from(value)).add_type("Checkpoint")
+    }
+}
+
+impl TryFrom<Envelope> for Checkpoint {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Envelope) -> Result<Self, Self::Error> {
+        value.check_type_envelope("Checkpoint")?;
+        value.extract_subject()
+    }
+}
+
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for Checkpoint {
+    fn random() -> Self {
+        Self {
+            height: BlockHeight::random(),
+            hash: BlockHash::random(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod envelope_tests {
+    use crate::test_envelope_roundtrip;
+
+    use super::Checkpoint;
+
+    test_envelope_roundtrip!(Checkpoint);
+}
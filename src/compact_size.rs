@@ -0,0 +1,40 @@
+/// Returns the number of bytes used by the Bitcoin-style CompactSize encoding of `n`.
+///
+/// CompactSize is the variable-length integer used to prefix scripts, vectors and other
+/// variable-length fields in Zcash's consensus serialization:
+///
+/// - values below `0xfd` take 1 byte,
+/// - values up to `0xffff` take 3 bytes (`0xfd` followed by a `u16`),
+/// - values up to `0xffff_ffff` take 5 bytes (`0xfe` followed by a `u32`), and
+/// - larger values take 9 bytes (`0xff` followed by a `u64`).
+///
+/// # Examples
+/// ```
+/// # use zewif::compact_size_len;
+/// assert_eq!(compact_size_len(252), 1);
+/// assert_eq!(compact_size_len(253), 3);
+/// ```
+pub fn compact_size_len(n: usize) -> usize {
+    match n as u64 {
+        0..=0xfc => 1,
+        0xfd..=0xffff => 3,
+        0x1_0000..=0xffff_ffff => 5,
+        _ => 9,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compact_size_len;
+
+    #[test]
+    fn test_compact_size_len() {
+        assert_eq!(compact_size_len(0), 1);
+        assert_eq!(compact_size_len(0xfc), 1);
+        assert_eq!(compact_size_len(0xfd), 3);
+        assert_eq!(compact_size_len(0xffff), 3);
+        assert_eq!(compact_size_len(0x1_0000), 5);
+        assert_eq!(compact_size_len(0xffff_ffff), 5);
+        assert_eq!(compact_size_len(0x1_0000_0000), 9);
+    }
+}
@@ -1,3 +1,4 @@
+use std::io::{self, Read};
 use std::ops::{
     Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
 };
@@ -171,6 +172,26 @@ impl Data {
         Ok(Self(hex::decode(hex)?))
     }
 
+    /// Encodes the data as padded base64 with the standard alphabet (RFC 4648).
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::Data;
+    /// let data = Data::from_slice(b"foobar");
+    /// assert_eq!(data.to_base64(), "Zm9vYmFy");
+    /// ```
+    pub fn to_base64(&self) -> String {
+        crate::base64::encode(&self.0)
+    }
+
+    /// Creates a `Data` instance from a padded base64 string with the standard alphabet.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not valid padded base64.
+    pub fn from_base64(base64: &str) -> Result<Self> {
+        Ok(Self(crate::base64::decode(base64)?))
+    }
+
     /// Concatenates multiple byte arrays into a single `Data` instance.
     ///
     /// This is useful for combining multiple binary data sources into one.
@@ -192,6 +213,36 @@ impl Data {
         }
         Self(bytes)
     }
+
+    /// Reads exactly `len` bytes from any source implementing the `Read` trait.
+    ///
+    /// This is useful for length-prefixed fields, where the length has already been read
+    /// from the stream.
+    ///
+    /// # Errors
+    /// Returns an IO error if reading fails or if fewer than `len` bytes are available.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::io::Cursor;
+    /// # use zewif::Data;
+    /// let mut cursor = Cursor::new(vec![1, 2, 3, 4]);
+    /// let data = Data::read_exact_from(&mut cursor, 3).unwrap();
+    /// assert_eq!(data.to_vec(), vec![1, 2, 3]);
+    /// ```
+    pub fn read_exact_from(mut reader: impl Read, len: usize) -> io::Result<Data> {
+        let mut bytes = vec![0u8; len];
+        reader.read_exact(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+
+    /// Overwrites the bytes with zeros and empties the buffer.
+    ///
+    /// Used by types holding secret material to wipe it when they are dropped.
+    pub(crate) fn wipe(&mut self) {
+        bc_crypto::memzero(&mut self.0);
+        self.0.clear();
+    }
 }
 
 impl Default for Data {
@@ -375,25 +426,67 @@ impl TryFrom<Envelope> for Data {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for Data {
+    fn random_with_size(size: usize) -> Self
+    where
+        Self: Sized,
+    {
+        bc_rand::random_data(size).into()
+    }
+
+    fn random() -> Self {
+        Self::random_with_size(32)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::io::{Cursor, ErrorKind};
+
     use crate::{test_cbor_roundtrip, test_envelope_roundtrip};
 
     use super::Data;
 
-    impl crate::RandomInstance for Data {
-        fn random_with_size(size: usize) -> Self
-        where
-            Self: Sized,
-        {
-            bc_rand::random_data(size).into()
-        }
+    test_cbor_roundtrip!(Data);
+    test_envelope_roundtrip!(Data);
 
-        fn random() -> Self {
-            Self::random_with_size(32)
+    #[test]
+    fn test_base64_roundtrip() {
+        for (bytes, encoded) in [
+            (&b""[..], ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+        ] {
+            let data = Data::from_slice(bytes);
+            assert_eq!(data.to_base64(), encoded);
+            assert_eq!(Data::from_base64(encoded).unwrap(), data);
         }
+
+        let all = Data::from_vec((0..=255).collect());
+        assert_eq!(Data::from_base64(&all.to_base64()).unwrap(), all);
+
+        assert!(Data::from_base64("Zg=").is_err());
+        assert!(Data::from_base64("Zg==Zg==").is_err());
+        assert!(Data::from_base64("Zm9*").is_err());
     }
 
-    test_cbor_roundtrip!(Data);
-    test_envelope_roundtrip!(Data);
+    #[test]
+    fn test_read_exact_from() {
+        let mut cursor = Cursor::new(vec![1, 2, 3]);
+        let data = Data::read_exact_from(&mut cursor, 3).unwrap();
+        assert_eq!(data.to_vec(), vec![1, 2, 3]);
+
+        let empty = Data::read_exact_from(&mut cursor, 0).unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_read_exact_from_short_input() {
+        let cursor = Cursor::new(vec![1, 2]);
+        let error = Data::read_exact_from(cursor, 3).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+    }
 }
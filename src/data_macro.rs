@@ -150,7 +150,7 @@ macro_rules! data {
             }
         }
 
-        #[cfg(test)]
+        #[cfg(any(test, feature = "test-dependencies"))]
         impl $crate::RandomInstance for $name {
             fn random() -> Self {
                 Self($crate::Data::random())
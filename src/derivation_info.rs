@@ -1,4 +1,4 @@
-use anyhow::Context;
+use anyhow::{Context, Result, bail};
 use bc_envelope::prelude::*;
 
 use crate::NonHardenedChildIndex;
@@ -103,6 +103,52 @@ impl DerivationInfo {
     pub fn address_index(&self) -> NonHardenedChildIndex {
         self.address_index
     }
+
+    /// Extracts the change and address index components from an HD derivation path string.
+    ///
+    /// The path must start with `m` and end in two non-hardened components, which become the
+    /// change and address index respectively. Hardened components may be marked with either
+    /// `'` or `h`.
+    ///
+    /// # Errors
+    /// Returns an error if the path is malformed, has fewer than two components after `m`, or
+    /// if either of its last two components is hardened or out of the non-hardened range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::DerivationInfo;
+    /// let info = DerivationInfo::from_path_string("m/44'/133'/0'/1/7").unwrap();
+    /// assert_eq!(u32::from(info.change()), 1);
+    /// assert_eq!(u32::from(info.address_index()), 7);
+    ///
+    /// // Fully hardened paths carry no non-hardened components
+    /// assert!(DerivationInfo::from_path_string("m/32'/133'/0'").is_err());
+    /// ```
+    pub fn from_path_string(path: &str) -> Result<Self> {
+        let mut components = path.split('/');
+        if components.next() != Some("m") {
+            bail!("Derivation path must start with \"m\": {}", path);
+        }
+        let components: Vec<&str> = components.collect();
+        if components.len() < 2 {
+            bail!("Derivation path has too few components: {}", path);
+        }
+        let parse = |component: &str| -> Result<NonHardenedChildIndex> {
+            if component.ends_with('\'') || component.ends_with('h') {
+                bail!("Expected a non-hardened component, found {}", component);
+            }
+            let value: u32 = component
+                .parse()
+                .with_context(|| format!("Invalid derivation path component: {}", component))?;
+            if value >= 0x8000_0000 {
+                bail!("Derivation path component out of range: {}", value);
+            }
+            Ok(NonHardenedChildIndex::from(value))
+        };
+        let change = parse(components[components.len() - 2])?;
+        let address_index = parse(components[components.len() - 1])?;
+        Ok(Self::new(change, address_index))
+    }
 }
 
 impl From<DerivationInfo> for Envelope {
@@ -131,20 +177,35 @@ impl TryFrom<Envelope> for DerivationInfo {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for DerivationInfo {
+    fn random() -> Self {
+        Self {
+            change: NonHardenedChildIndex::random(),
+            address_index: NonHardenedChildIndex::random(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{NonHardenedChildIndex, test_envelope_roundtrip};
+    use crate::test_envelope_roundtrip;
 
     use super::DerivationInfo;
 
-    impl crate::RandomInstance for DerivationInfo {
-        fn random() -> Self {
-            Self {
-                change: NonHardenedChildIndex::random(),
-                address_index: NonHardenedChildIndex::random(),
-            }
-        }
-    }
-
     test_envelope_roundtrip!(DerivationInfo);
+
+    #[test]
+    fn test_from_path_string() {
+        let info = DerivationInfo::from_path_string("m/32'/1'/0'/0/5").unwrap();
+        assert_eq!(info, DerivationInfo::new(0u32.into(), 5u32.into()));
+        let info = DerivationInfo::from_path_string("m/44h/133h/2h/1/0").unwrap();
+        assert_eq!(info, DerivationInfo::new(1u32.into(), 0u32.into()));
+
+        assert!(DerivationInfo::from_path_string("32'/1'/0'/0/5").is_err());
+        assert!(DerivationInfo::from_path_string("m/5").is_err());
+        assert!(DerivationInfo::from_path_string("m/0/5'").is_err());
+        assert!(DerivationInfo::from_path_string("m/0/x").is_err());
+        assert!(DerivationInfo::from_path_string("m/0/2147483648").is_err());
+    }
 }
@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+/// The kind of difference recorded by a [`DiffEntry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiffKind {
+    /// The item is present only in the other container.
+    Added,
+    /// The item is present only in this container.
+    Removed,
+    /// The item is present in both containers but its contents differ.
+    Changed,
+}
+
+/// A single structural difference found by [`Zewif::diff`](crate::Zewif::diff).
+///
+/// The path locates the item within the container, e.g.
+/// `wallets[0].accounts[1].addresses[zs1...]` or `transactions[<txid>]`. Wallets and accounts
+/// are identified by index, addresses by their string form, and transactions by their ID.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DiffEntry {
+    path: String,
+    kind: DiffKind,
+}
+
+impl DiffEntry {
+    pub(crate) fn new(path: impl Into<String>, kind: DiffKind) -> Self {
+        Self {
+            path: path.into(),
+            kind,
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn kind(&self) -> DiffKind {
+        self.kind
+    }
+}
+
+impl std::fmt::Display for DiffEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let marker = match self.kind {
+            DiffKind::Added => '+',
+            DiffKind::Removed => '-',
+            DiffKind::Changed => '~',
+        };
+        write!(f, "{} {}", marker, self.path)
+    }
+}
+
+/// Appends `.field` to `path`, or returns `field` alone at the root.
+pub(crate) fn diff_path(path: &str, field: impl Display) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+/// Compares two keyed collections, recording added and removed keys and calling `on_both`
+/// with the item path for keys present in both.
+///
+/// Keys are visited in order so that the resulting entries are deterministic.
+pub(crate) fn diff_keyed<K: Ord + Display, V>(
+    path: &str,
+    collection: &str,
+    ours: BTreeMap<K, V>,
+    mut theirs: BTreeMap<K, V>,
+    diffs: &mut Vec<DiffEntry>,
+    mut on_both: impl FnMut(&str, V, V, &mut Vec<DiffEntry>),
+) {
+    let item_path = |key: &K| diff_path(path, format!("{}[{}]", collection, key));
+    for (key, value) in ours {
+        let item = item_path(&key);
+        match theirs.remove(&key) {
+            Some(other) => on_both(&item, value, other, diffs),
+            None => diffs.push(DiffEntry::new(item, DiffKind::Removed)),
+        }
+    }
+    for key in theirs.keys() {
+        diffs.push(DiffEntry::new(item_path(key), DiffKind::Added));
+    }
+}
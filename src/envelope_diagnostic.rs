@@ -0,0 +1,39 @@
+use bc_envelope::prelude::*;
+
+/// Returns the envelope notation for any value that converts to an [`Envelope`].
+///
+/// This is intended for debugging envelope round-trips and for including the exact serialized
+/// structure of a value in bug reports. The value is cloned before conversion.
+///
+/// # Examples
+/// ```
+/// # use zewif::{Amount, envelope_diagnostic};
+/// # use anyhow::Result;
+/// # fn example() -> Result<()> {
+/// let amount = Amount::from_u64(5000)?;
+/// assert_eq!(envelope_diagnostic(&amount), "5000");
+/// # Ok(())
+/// # }
+/// ```
+pub fn envelope_diagnostic<T: Into<Envelope> + Clone>(value: &T) -> String {
+    let envelope: Envelope = value.clone().into();
+    envelope.format()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::envelope_diagnostic;
+    use crate::{Amount, sapling::SaplingSentOutput};
+
+    #[test]
+    fn test_envelope_diagnostic() {
+        let amount = Amount::from_u64(5000).unwrap();
+        assert!(envelope_diagnostic(&amount).contains("5000"));
+
+        let mut output = SaplingSentOutput::new();
+        output.set_value(amount);
+        let diagnostic = envelope_diagnostic(&output);
+        assert!(diagnostic.contains("\"SaplingSentOutput\""));
+        assert!(diagnostic.contains("\"value\": 5000"));
+    }
+}
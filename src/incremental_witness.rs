@@ -1,3 +1,5 @@
+use anyhow::{Result, bail};
+
 /// A Merkle path to a specific note commitment in a Merkle tree, along with metadata about the
 /// state of the tree at the time the Merkle path was computed.
 ///
@@ -111,29 +113,247 @@ impl<const DEPTH: usize, Node> IncrementalWitness<DEPTH, Node> {
     pub fn anchor_frontier(&self) -> &[Node] {
         &self.anchor_frontier
     }
+
+    /// Checks that the witness is internally consistent with a tree of depth `DEPTH`.
+    ///
+    /// The note's leaf position is available via [`note_position`](Self::note_position). This
+    /// method verifies that:
+    ///
+    /// - the Merkle path contains exactly `DEPTH` nodes, one per level from leaf to root,
+    /// - the anchor tree size does not exceed the capacity of a tree of depth `DEPTH`,
+    /// - the note position lies within the tree as of the anchor, and
+    /// - the anchor frontier has no more than `DEPTH` nodes.
+    ///
+    /// This does not recompute any hashes; it only detects structurally malformed witnesses.
+    pub fn validate(&self) -> Result<()> {
+        if self.merkle_path.len() != DEPTH {
+            bail!(
+                "Merkle path has {} nodes, expected {}",
+                self.merkle_path.len(),
+                DEPTH
+            );
+        }
+        let capacity = 1u64.checked_shl(DEPTH as u32).unwrap_or(u64::MAX);
+        if u64::from(self.anchor_tree_size) > capacity {
+            bail!(
+                "Anchor tree size {} exceeds the capacity of a depth {} tree",
+                self.anchor_tree_size,
+                DEPTH
+            );
+        }
+        if self.note_position >= self.anchor_tree_size {
+            bail!(
+                "Note position {} is outside the anchor tree of size {}",
+                self.note_position,
+                self.anchor_tree_size
+            );
+        }
+        if self.anchor_frontier.len() > DEPTH {
+            bail!(
+                "Anchor frontier has {} nodes, expected at most {}",
+                self.anchor_frontier.len(),
+                DEPTH
+            );
+        }
+        Ok(())
+    }
+
+    /// Appends a note commitment to the tree, advancing the witness past it.
+    ///
+    /// The anchor frontier is taken to hold the roots of the complete subtrees that make up
+    /// the tree, one for each set bit of [`anchor_tree_size`](Self::anchor_tree_size), ordered
+    /// from leaf to root. The new leaf is folded into the frontier, the tree size grows by one,
+    /// and any node of the Merkle path whose subtree becomes complete is filled in.
+    ///
+    /// `combine(level, left, right)` must return the parent of two nodes at `level`, where
+    /// level 0 is the leaves; it is supplied by the caller so that this crate does not need to
+    /// implement each protocol's hash function.
+    ///
+    /// The [`anchor`](Self::anchor) is not recomputed, since that would require the protocol's
+    /// empty-subtree hashes. For the same reason, a Merkle path node whose subtree is only
+    /// partly filled keeps its previous value until the subtree is complete.
+    ///
+    /// # Errors
+    /// Returns an error if the witness fails [`validate`](Self::validate), if the tree is
+    /// already full, or if the frontier does not match the tree size.
+    pub fn append(
+        &mut self,
+        commitment: Node,
+        combine: impl Fn(u8, &Node, &Node) -> Node,
+    ) -> Result<()>
+    where
+        Node: Clone,
+    {
+        self.validate()?;
+        let position = self.anchor_tree_size;
+        let capacity = 1u64.checked_shl(DEPTH as u32).unwrap_or(u64::MAX);
+        if u64::from(position) >= capacity || position == u32::MAX {
+            bail!("Cannot append to a full tree of depth {}", DEPTH);
+        }
+        if self.anchor_frontier.len() != position.count_ones() as usize {
+            bail!(
+                "Anchor frontier has {} nodes, but a tree of size {} needs {}",
+                self.anchor_frontier.len(),
+                position,
+                position.count_ones()
+            );
+        }
+
+        // Appending a leaf carries like a binary increment: each complete subtree at a level
+        // where the size has a set bit is merged with the new node into the level above.
+        let carries = position.trailing_ones() as usize;
+        let merged: Vec<Node> = self.anchor_frontier.drain(..carries).collect();
+        let mut node = commitment;
+        self.complete_path_node(0, position, &node);
+        for (level, left) in merged.iter().enumerate() {
+            node = combine(level as u8, left, &node);
+            if level + 1 < DEPTH {
+                self.complete_path_node(level + 1, position, &node);
+            }
+        }
+        self.anchor_frontier.insert(0, node);
+        self.anchor_tree_size = position + 1;
+        Ok(())
+    }
+
+    /// Records `node`, the newly completed subtree at `level` containing leaf `position`, in
+    /// the Merkle path if it is the right-hand sibling of the note's ancestor at that level.
+    fn complete_path_node(&mut self, level: usize, position: u32, node: &Node)
+    where
+        Node: Clone,
+    {
+        let note_index = self.note_position >> level;
+        if note_index & 1 == 0 && position >> level == note_index + 1 {
+            self.merkle_path[level] = node.clone();
+        }
+    }
+}
+
+#[cfg(any(test, feature = "test-dependencies"))]
+impl<const DEPTH: usize, Node: crate::RandomInstance> crate::RandomInstance
+    for IncrementalWitness<DEPTH, Node>
+{
+    fn random() -> Self {
+        use bc_rand::rng_next_with_upper_bound;
+
+        let mut rng = bc_rand::thread_rng();
+        let note_position = rng_next_with_upper_bound(&mut rng, u32::MAX / 4);
+        let anchor_tree_size = note_position + rng_next_with_upper_bound(&mut rng, u32::MAX / 16);
+        Self {
+            note_commitment: Node::random(),
+            note_position,
+            merkle_path: Vec::random(), // TODO: this should have DEPTH entries
+            anchor: Node::random(),
+            anchor_tree_size,
+            anchor_frontier: Vec::random(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use bc_rand::rng_next_with_upper_bound;
-
     use super::IncrementalWitness;
-    use crate::RandomInstance;
-
-    impl<const DEPTH: usize, Node: RandomInstance> RandomInstance for IncrementalWitness<DEPTH, Node> {
-        fn random() -> Self {
-            let mut rng = bc_rand::thread_rng();
-            let note_position = rng_next_with_upper_bound(&mut rng, u32::MAX / 4);
-            let anchor_tree_size =
-                note_position + rng_next_with_upper_bound(&mut rng, u32::MAX / 16);
-            Self {
-                note_commitment: Node::random(),
-                note_position,
-                merkle_path: Vec::random(), // TODO: this should have DEPTH entries
-                anchor: Node::random(),
-                anchor_tree_size,
-                anchor_frontier: Vec::random(),
-            }
-        }
+
+    fn valid_witness() -> IncrementalWitness<32, [u8; 32]> {
+        IncrementalWitness::from_parts(
+            [0u8; 32],
+            12345,
+            vec![[1u8; 32]; 32],
+            [2u8; 32],
+            67891,
+            vec![[3u8; 32]; 4],
+        )
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(valid_witness().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_short_merkle_path() {
+        let mut witness = valid_witness();
+        witness.merkle_path.pop();
+        assert!(witness.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_position_outside_tree() {
+        let mut witness = valid_witness();
+        witness.note_position = witness.anchor_tree_size;
+        assert!(witness.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_oversized_frontier() {
+        let mut witness = valid_witness();
+        witness.anchor_frontier = vec![[3u8; 32]; 33];
+        assert!(witness.validate().is_err());
+    }
+
+    // Renders each parent as `level(leftright)`, so the tree shape can be checked directly.
+    fn combine() -> impl Fn(u8, &String, &String) -> String {
+        |level, left, right| format!("{}({}{})", level, left, right)
+    }
+
+    #[test]
+    fn test_append() {
+        let mut witness = IncrementalWitness::<4, String>::from_parts(
+            "a".to_string(),
+            0,
+            ["e0", "e1", "e2", "e3"].map(String::from).to_vec(),
+            "root".to_string(),
+            1,
+            vec!["a".to_string()],
+        );
+
+        witness.append("b".to_string(), combine()).unwrap();
+        assert_eq!(witness.anchor_tree_size(), 2);
+        assert_eq!(witness.anchor_frontier(), ["0(ab)"]);
+        assert_eq!(witness.merkle_path(), ["b", "e1", "e2", "e3"]);
+
+        witness.append("c".to_string(), combine()).unwrap();
+        assert_eq!(witness.anchor_frontier(), ["c", "0(ab)"]);
+        assert_eq!(witness.merkle_path(), ["b", "e1", "e2", "e3"]);
+
+        witness.append("d".to_string(), combine()).unwrap();
+        assert_eq!(witness.anchor_tree_size(), 4);
+        assert_eq!(witness.anchor_frontier(), ["1(0(ab)0(cd))"]);
+        assert_eq!(witness.merkle_path(), ["b", "0(cd)", "e2", "e3"]);
+        assert_eq!(witness.merkle_path().len(), 4);
+        assert!(witness.validate().is_ok());
+    }
+
+    #[test]
+    fn test_append_errors() {
+        let full = |size: u32, frontier: Vec<String>| {
+            IncrementalWitness::<2, String>::from_parts(
+                "a".to_string(),
+                0,
+                vec!["e0".to_string(), "e1".to_string()],
+                "root".to_string(),
+                size,
+                frontier,
+            )
+        };
+        let mut witness = full(4, vec!["root".to_string()]);
+        assert!(witness.append("e".to_string(), combine()).is_err());
+
+        let mut witness = full(3, vec!["c".to_string()]);
+        assert!(witness.append("d".to_string(), combine()).is_err());
+        assert_eq!(witness.anchor_tree_size(), 3);
+    }
+
+    #[test]
+    fn test_validate_tree_too_large() {
+        let witness = IncrementalWitness::<4, [u8; 32]>::from_parts(
+            [0u8; 32],
+            3,
+            vec![[1u8; 32]; 4],
+            [2u8; 32],
+            17,
+            vec![],
+        );
+        assert!(witness.validate().is_err());
     }
 }
@@ -38,9 +38,16 @@ impl LegacySeed {
     }
 }
 
+/// The seed bytes are overwritten with zeros when a `LegacySeed` is dropped.
+impl Drop for LegacySeed {
+    fn drop(&mut self) {
+        self.seed_data.wipe();
+    }
+}
+
 impl From<LegacySeed> for Envelope {
-    fn from(value: LegacySeed) -> Self {
-        Envelope::new(value.seed_data)
+    fn from(mut value: LegacySeed) -> Self {
+        Envelope::new(std::mem::take(&mut value.seed_data))
             .add_type("LegacySeed")
             .add_optional_assertion("fingerprint", value.fingerprint)
     }
@@ -64,20 +71,31 @@ impl TryFrom<Envelope> for LegacySeed {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for LegacySeed {
+    fn random() -> Self {
+        Self {
+            seed_data: Data::random(),
+            fingerprint: SeedFingerprint::opt_random(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Data, SeedFingerprint, test_envelope_roundtrip};
+    use crate::{Data, test_envelope_roundtrip};
 
     use super::LegacySeed;
 
-    impl crate::RandomInstance for LegacySeed {
-        fn random() -> Self {
-            Self {
-                seed_data: Data::random(),
-                fingerprint: SeedFingerprint::opt_random(),
-            }
-        }
-    }
-
     test_envelope_roundtrip!(LegacySeed);
+
+    // Whether the freed memory was actually zeroed cannot be observed without undefined
+    // behavior, so this only checks that wiping one value leaves its clone intact.
+    #[test]
+    fn test_drop_does_not_affect_clones() {
+        let seed = LegacySeed::new(Data::from_vec(vec![0xaa; 32]), None);
+        let copy = seed.clone();
+        drop(seed);
+        assert_eq!(copy.seed_data().to_vec(), vec![0xaa; 32]);
+    }
 }
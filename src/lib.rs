@@ -59,6 +59,12 @@
 //! wallet.add_account(account);
 //! zewif.add_wallet(wallet);
 //! ```
+//!
+//! ## Feature Flags
+//!
+//! - `test-dependencies`: Exports the `RandomInstance` trait, with implementations for the
+//!   public ZeWIF types, and the `test_envelope_roundtrip`/`test_cbor_roundtrip` helpers, so
+//!   that downstream crates can generate random ZeWIF values for their own property tests.
 
 // Macros
 mod blob_macro;
@@ -68,6 +74,11 @@ mod mod_use_macro;
 mod string_macro;
 mod test_roundtrip_macros;
 
+// Internal helpers
+mod base58;
+mod base64;
+mod predicate;
+
 // Test utilities
 #[cfg(any(test, feature = "test-dependencies"))]
 mod_use!(test_utils);
@@ -82,30 +93,44 @@ mod_use!(account);
 mod_use!(address);
 mod_use!(amount);
 mod_use!(anchor);
+mod_use!(attachment_stats);
+mod_use!(attachments_ext);
 mod_use!(bip_39_mnemonic);
 mod_use!(blob);
 mod_use!(block_hash);
 mod_use!(block_height);
+mod_use!(checkpoint);
+mod_use!(compact_size);
 mod_use!(data);
 mod_use!(derivation_info);
+mod_use!(diff_entry);
+mod_use!(envelope_diagnostic);
 mod_use!(incremental_witness);
 mod_use!(indexed);
 mod_use!(memo);
 mod_use!(mnemonic_language);
 mod_use!(network);
+mod_use!(network_upgrade);
 mod_use!(non_hardened_child_index);
 mod_use!(protocol_address);
 mod_use!(script);
+mod_use!(script_builder);
+mod_use!(script_op);
 mod_use!(legacy_seed);
+mod_use!(lock_time);
 mod_use!(seed_material);
 mod_use!(seed_fingerprint);
+mod_use!(size_report);
 mod_use!(string_utils);
 mod_use!(transaction);
 mod_use!(tx_block_position);
 mod_use!(txid);
 mod_use!(unified_address);
+mod_use!(validation_warning);
+mod_use!(zewif_builder);
 mod_use!(zewif_envelope);
 mod_use!(zewif_impl);
+mod_use!(zewif_visitor);
 mod_use!(zewif_wallet);
 
 pub use blob::HexParseError;
@@ -0,0 +1,64 @@
+use crate::BlockHeight;
+
+/// The threshold below which an `nLockTime` value is a block height rather than a timestamp.
+pub const LOCK_TIME_THRESHOLD: u32 = 500_000_000;
+
+/// The interpretation of a transaction's `nLockTime` field.
+///
+/// The consensus rules read a lock time below 500,000,000 as the block height before which
+/// the transaction cannot be mined, and any other value as a Unix timestamp (in seconds).
+/// A lock time of zero places no restriction on the transaction.
+///
+/// # Examples
+/// ```
+/// # use zewif::{BlockHeight, LockTime};
+/// assert_eq!(
+///     LockTime::interpret(2_500_000),
+///     LockTime::Height(BlockHeight::from_u32(2_500_000))
+/// );
+/// assert_eq!(
+///     LockTime::interpret(1_700_000_000),
+///     LockTime::Timestamp(1_700_000_000)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LockTime {
+    /// The transaction cannot be mined before this block height.
+    Height(BlockHeight),
+    /// The transaction cannot be mined before this Unix time, in seconds.
+    Timestamp(u32),
+}
+
+impl LockTime {
+    /// Interprets a raw `nLockTime` value as either a block height or a timestamp.
+    pub fn interpret(lock_time: u32) -> Self {
+        if lock_time < LOCK_TIME_THRESHOLD {
+            LockTime::Height(BlockHeight::from_u32(lock_time))
+        } else {
+            LockTime::Timestamp(lock_time)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LOCK_TIME_THRESHOLD, LockTime};
+    use crate::BlockHeight;
+
+    #[test]
+    fn test_interpret_boundary() {
+        assert_eq!(
+            LockTime::interpret(0),
+            LockTime::Height(BlockHeight::from_u32(0))
+        );
+        assert_eq!(
+            LockTime::interpret(LOCK_TIME_THRESHOLD - 1),
+            LockTime::Height(BlockHeight::from_u32(LOCK_TIME_THRESHOLD - 1))
+        );
+        assert_eq!(
+            LockTime::interpret(LOCK_TIME_THRESHOLD),
+            LockTime::Timestamp(LOCK_TIME_THRESHOLD)
+        );
+        assert_eq!(LockTime::interpret(u32::MAX), LockTime::Timestamp(u32::MAX));
+    }
+}
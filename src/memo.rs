@@ -5,3 +5,126 @@ use crate::{blob_envelope, data};
 data!(Memo, "A memo associated with a Zcash shielded output.");
 
 blob_envelope!(Memo);
+
+/// The interpretation of a memo's contents according to
+/// [ZIP 302](https://zips.z.cash/zip-0302).
+///
+/// The first byte of a memo determines how the remaining bytes are to be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoKind {
+    /// No memo: a first byte of `0xF6` followed only by zero bytes (or no bytes at all).
+    Empty,
+    /// A UTF-8 text memo: a first byte of `0xF4` or below, with the zero-padded bytes forming
+    /// valid UTF-8.
+    Text,
+    /// Arbitrary data: a first byte of `0xF5` (data in an agreed-upon format) or `0xFF`
+    /// (unspecified data). The first byte is carried so the two can be distinguished.
+    Arbitrary(u8),
+    /// Contents reserved for future use (`0xF6` with non-zero trailing bytes, or `0xF7` through
+    /// `0xFE`), or text bytes that are not valid UTF-8.
+    Future,
+}
+
+impl Memo {
+    /// Classifies this memo according to the ZIP 302 rules.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::{Memo, MemoKind};
+    /// let mut bytes = b"hello".to_vec();
+    /// bytes.resize(512, 0);
+    /// assert_eq!(Memo::new(bytes).kind(), MemoKind::Text);
+    ///
+    /// let mut bytes = vec![0xf6];
+    /// bytes.resize(512, 0);
+    /// assert_eq!(Memo::new(bytes).kind(), MemoKind::Empty);
+    /// ```
+    pub fn kind(&self) -> MemoKind {
+        let bytes: &[u8] = self.as_ref();
+        match bytes.first().copied() {
+            None => MemoKind::Empty,
+            Some(first) if first <= 0xf4 => {
+                let end = bytes.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+                if std::str::from_utf8(&bytes[..end]).is_ok() {
+                    MemoKind::Text
+                } else {
+                    MemoKind::Future
+                }
+            }
+            Some(0xf6) if bytes[1..].iter().all(|&b| b == 0) => MemoKind::Empty,
+            Some(first @ (0xf5 | 0xff)) => MemoKind::Arbitrary(first),
+            Some(_) => MemoKind::Future,
+        }
+    }
+
+    /// Compares two memos, ignoring trailing zero padding when both are text memos.
+    ///
+    /// Text memos are zero-padded to a fixed length, so the same text may be stored with
+    /// different amounts of padding. All other memo kinds are compared byte for byte, as `==`
+    /// does.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::Memo;
+    /// let mut padded = b"hello".to_vec();
+    /// padded.resize(512, 0);
+    /// let a = Memo::new(padded);
+    /// let b = Memo::new(b"hello".to_vec());
+    /// assert!(a.semantic_eq(&b));
+    /// assert_ne!(a, b);
+    /// ```
+    pub fn semantic_eq(&self, other: &Memo) -> bool {
+        if self.kind() == MemoKind::Text && other.kind() == MemoKind::Text {
+            let trim = |bytes: &[u8]| -> usize {
+                bytes.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1)
+            };
+            let (a, b): (&[u8], &[u8]) = (self.as_ref(), other.as_ref());
+            a[..trim(a)] == b[..trim(b)]
+        } else {
+            self == other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Memo, MemoKind};
+
+    fn memo(prefix: &[u8]) -> Memo {
+        let mut bytes = prefix.to_vec();
+        bytes.resize(512, 0);
+        Memo::new(bytes)
+    }
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(memo(&[0xf6]).kind(), MemoKind::Empty);
+        assert_eq!(Memo::new(vec![]).kind(), MemoKind::Empty);
+        assert_eq!(
+            memo("Thanks for the coffee ☕".as_bytes()).kind(),
+            MemoKind::Text
+        );
+        assert_eq!(memo(&[]).kind(), MemoKind::Text);
+        assert_eq!(memo(&[0xf5, 1, 2, 3]).kind(), MemoKind::Arbitrary(0xf5));
+        assert_eq!(memo(&[0xff, 1, 2, 3]).kind(), MemoKind::Arbitrary(0xff));
+        assert_eq!(memo(&[0xf6, 1]).kind(), MemoKind::Future);
+        assert_eq!(memo(&[0xf7]).kind(), MemoKind::Future);
+        assert_eq!(memo(&[0xfe]).kind(), MemoKind::Future);
+        assert_eq!(memo(&[0x61, 0xc3, 0x28]).kind(), MemoKind::Future);
+    }
+
+    #[test]
+    fn test_semantic_eq() {
+        let long = memo(b"hello");
+        let mut short = b"hello".to_vec();
+        short.resize(16, 0);
+        let short = Memo::new(short);
+        assert_ne!(long, short);
+        assert!(long.semantic_eq(&short));
+        assert!(!long.semantic_eq(&memo(b"hello!")));
+
+        let data = Memo::new(vec![0xf5, 1, 0]);
+        assert!(!data.semantic_eq(&Memo::new(vec![0xf5, 1])));
+        assert!(data.semantic_eq(&data.clone()));
+    }
+}
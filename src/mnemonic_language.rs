@@ -1,6 +1,11 @@
+use std::sync::LazyLock;
+
 use anyhow::{Context, Result, bail};
 use bc_envelope::prelude::*;
 
+static ENGLISH_WORDLIST: LazyLock<Vec<&'static str>> =
+    LazyLock::new(|| include_str!("wordlists/english.txt").lines().collect());
+
 /// The language used for BIP-39/BIP-44 mnemonic seed phrases in a wallet.
 ///
 /// `MnemonicLanguage` represents the human language in which a wallet's recovery
@@ -110,6 +115,29 @@ impl MnemonicLanguage {
         }
     }
 
+    /// Returns every supported language, in order of their numeric identifiers.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::MnemonicLanguage;
+    /// assert_eq!(MnemonicLanguage::all()[0], MnemonicLanguage::English);
+    /// assert_eq!(MnemonicLanguage::all().len(), 10);
+    /// ```
+    pub fn all() -> &'static [MnemonicLanguage] {
+        &[
+            MnemonicLanguage::English,
+            MnemonicLanguage::SimplifiedChinese,
+            MnemonicLanguage::TraditionalChinese,
+            MnemonicLanguage::Czech,
+            MnemonicLanguage::French,
+            MnemonicLanguage::Italian,
+            MnemonicLanguage::Japanese,
+            MnemonicLanguage::Korean,
+            MnemonicLanguage::Portuguese,
+            MnemonicLanguage::Spanish,
+        ]
+    }
+
     /// Returns the string name of the mnemonic language.
     ///
     /// This method returns the canonical name of the language as a static string,
@@ -142,6 +170,48 @@ impl MnemonicLanguage {
             MnemonicLanguage::Spanish => "Spanish",
         }
     }
+
+    /// Returns the BIP-39 wordlist for this language, if it is bundled with the crate.
+    ///
+    /// Only the English wordlist is currently bundled; all other languages return `None`.
+    fn wordlist(&self) -> Option<&'static [&'static str]> {
+        match self {
+            MnemonicLanguage::English => Some(ENGLISH_WORDLIST.as_slice()),
+            _ => None,
+        }
+    }
+
+    /// Returns the word at `index` in this language's BIP-39 wordlist.
+    ///
+    /// Returns `None` if `index` is not less than 2048, or if the wordlist for this language
+    /// is not bundled. Only English is currently bundled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::MnemonicLanguage;
+    /// assert_eq!(MnemonicLanguage::English.word_at(0), Some("abandon"));
+    /// assert_eq!(MnemonicLanguage::Japanese.word_at(0), None);
+    /// ```
+    pub fn word_at(&self, index: u16) -> Option<&'static str> {
+        self.wordlist()?.get(index as usize).copied()
+    }
+
+    /// Returns the index of `word` in this language's BIP-39 wordlist.
+    ///
+    /// Returns `None` if the word is not in the list, or if the wordlist for this language
+    /// is not bundled. Only English is currently bundled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::MnemonicLanguage;
+    /// assert_eq!(MnemonicLanguage::English.index_of_word("zoo"), Some(2047));
+    /// assert_eq!(MnemonicLanguage::English.index_of_word("zcash"), None);
+    /// ```
+    pub fn index_of_word(&self, word: &str) -> Option<u16> {
+        // The English wordlist is sorted, so a binary search suffices.
+        let index = self.wordlist()?.binary_search_by(|w| (*w).cmp(word)).ok()?;
+        Some(index as u16)
+    }
 }
 
 /// Formats the mnemonic language as a human-readable string
@@ -224,18 +294,51 @@ impl TryFrom<Envelope> for MnemonicLanguage {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for MnemonicLanguage {
+    fn random() -> Self {
+        MnemonicLanguage::from_u32(rand::random::<u8>() as u32 % 10).unwrap()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{test_cbor_roundtrip, test_envelope_roundtrip};
 
     use super::MnemonicLanguage;
 
-    impl crate::RandomInstance for MnemonicLanguage {
-        fn random() -> Self {
-            MnemonicLanguage::from_u32(rand::random::<u8>() as u32 % 10).unwrap()
+    test_cbor_roundtrip!(MnemonicLanguage);
+    test_envelope_roundtrip!(MnemonicLanguage);
+
+    #[test]
+    fn test_all() {
+        let all = MnemonicLanguage::all();
+        assert_eq!(all.len(), 10);
+        for (value, &language) in all.iter().enumerate() {
+            assert_eq!(language as u32, value as u32);
+            assert_eq!(
+                MnemonicLanguage::from_u32(language as u32).unwrap(),
+                language
+            );
         }
     }
 
-    test_cbor_roundtrip!(MnemonicLanguage);
-    test_envelope_roundtrip!(MnemonicLanguage);
+    #[test]
+    fn test_english_wordlist() {
+        let english = MnemonicLanguage::English;
+        assert_eq!(english.word_at(0), Some("abandon"));
+        assert_eq!(english.word_at(2047), Some("zoo"));
+        assert_eq!(english.word_at(2048), None);
+        for index in [0, 1, 1024, 2047] {
+            let word = english.word_at(index).unwrap();
+            assert_eq!(english.index_of_word(word), Some(index));
+        }
+        assert_eq!(english.index_of_word("notaword"), None);
+    }
+
+    #[test]
+    fn test_unbundled_wordlist() {
+        assert_eq!(MnemonicLanguage::French.word_at(0), None);
+        assert_eq!(MnemonicLanguage::French.index_of_word("abaisser"), None);
+    }
 }
@@ -1,6 +1,8 @@
 use anyhow::{Context, Result, bail};
 use bc_envelope::prelude::*;
 
+use crate::{BlockHeight, NetworkUpgrade};
+
 /// Represents a Zcash network environment (mainnet, testnet, or regtest).
 ///
 /// The `Network` enum identifies which Zcash network a wallet, address,
@@ -44,6 +46,86 @@ pub enum Network {
     Regtest,
 }
 
+impl Network {
+    /// The Bech32 human-readable part used for Sapling payment addresses on this network.
+    pub fn sapling_hrp(&self) -> &'static str {
+        match self {
+            Network::Main => "zs",
+            Network::Test => "ztestsapling",
+            Network::Regtest => "zregtestsapling",
+        }
+    }
+
+    /// The Bech32m human-readable part used for Unified Addresses on this network.
+    pub fn unified_hrp(&self) -> &'static str {
+        match self {
+            Network::Main => "u",
+            Network::Test => "utest",
+            Network::Regtest => "uregtest",
+        }
+    }
+
+    /// The Base58Check version bytes prefixed to transparent P2PKH addresses on this network.
+    ///
+    /// Testnet and regtest share the same prefix.
+    pub fn transparent_pubkey_prefix(&self) -> [u8; 2] {
+        match self {
+            Network::Main => [0x1c, 0xb8],
+            Network::Test | Network::Regtest => [0x1d, 0x25],
+        }
+    }
+
+    /// The Base58Check version bytes prefixed to transparent P2SH addresses on this network.
+    ///
+    /// Testnet and regtest share the same prefix.
+    pub fn transparent_script_prefix(&self) -> [u8; 2] {
+        match self {
+            Network::Main => [0x1c, 0xbd],
+            Network::Test | Network::Regtest => [0x1c, 0xba],
+        }
+    }
+
+    /// The height at which `upgrade` activated on this network.
+    ///
+    /// Regtest activation heights are chosen by whoever runs the network, so this returns
+    /// `None` for [`Network::Regtest`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::{BlockHeight, Network, NetworkUpgrade};
+    /// assert_eq!(
+    ///     Network::Main.activation_height(NetworkUpgrade::Sapling),
+    ///     Some(BlockHeight::from_u32(419_200))
+    /// );
+    /// assert_eq!(Network::Regtest.activation_height(NetworkUpgrade::Sapling), None);
+    /// ```
+    pub fn activation_height(&self, upgrade: NetworkUpgrade) -> Option<BlockHeight> {
+        use NetworkUpgrade::*;
+        let height = match self {
+            Network::Main => match upgrade {
+                Overwinter => 347_500,
+                Sapling => 419_200,
+                Blossom => 653_600,
+                Heartwood => 903_000,
+                Canopy => 1_046_400,
+                Nu5 => 1_687_104,
+                Nu6 => 2_726_400,
+            },
+            Network::Test => match upgrade {
+                Overwinter => 207_500,
+                Sapling => 280_000,
+                Blossom => 584_000,
+                Heartwood => 903_800,
+                Canopy => 1_028_500,
+                Nu5 => 1_842_420,
+                Nu6 => 2_976_000,
+            },
+            Network::Regtest => return None,
+        };
+        Some(BlockHeight::from_u32(height))
+    }
+}
+
 impl From<Network> for String {
     fn from(value: Network) -> String {
         match value {
@@ -54,6 +136,29 @@ impl From<Network> for String {
     }
 }
 
+/// Formats the network as its canonical lowercase name: `main`, `test` or `regtest`.
+impl std::fmt::Display for Network {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", String::from(*self))
+    }
+}
+
+/// Parses a network name, as given on a command line.
+///
+/// Accepts `main` or `mainnet`, `test` or `testnet`, and `regtest`, in any case.
+impl std::str::FromStr for Network {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "main" | "mainnet" => Ok(Network::Main),
+            "test" | "testnet" => Ok(Network::Test),
+            "regtest" => Ok(Network::Regtest),
+            _ => bail!("Unknown network: {}", s),
+        }
+    }
+}
+
 impl TryFrom<String> for Network {
     type Error = anyhow::Error;
 
@@ -99,22 +204,77 @@ impl TryFrom<Envelope> for Network {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for Network {
+    fn random() -> Self {
+        match rand::random::<u8>() % 3 {
+            0 => Network::Main,
+            1 => Network::Test,
+            _ => Network::Regtest,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{test_cbor_roundtrip, test_envelope_roundtrip};
+    use crate::{BlockHeight, NetworkUpgrade, test_cbor_roundtrip, test_envelope_roundtrip};
 
     use super::Network;
 
-    impl crate::RandomInstance for Network {
-        fn random() -> Self {
-            match rand::random::<u8>() % 3 {
-                0 => Network::Main,
-                1 => Network::Test,
-                _ => Network::Regtest,
-            }
+    test_cbor_roundtrip!(Network);
+    test_envelope_roundtrip!(Network);
+
+    #[test]
+    fn test_from_str_and_display() {
+        for (s, network) in [
+            ("main", Network::Main),
+            ("mainnet", Network::Main),
+            ("MainNet", Network::Main),
+            ("test", Network::Test),
+            ("testnet", Network::Test),
+            ("TESTNET", Network::Test),
+            ("regtest", Network::Regtest),
+            ("RegTest", Network::Regtest),
+        ] {
+            assert_eq!(s.parse::<Network>().unwrap(), network);
         }
+        assert!("signet".parse::<Network>().is_err());
+        assert!("".parse::<Network>().is_err());
+
+        assert_eq!(Network::Main.to_string(), "main");
+        assert_eq!(Network::Test.to_string(), "test");
+        assert_eq!(Network::Regtest.to_string(), "regtest");
     }
 
-    test_cbor_roundtrip!(Network);
-    test_envelope_roundtrip!(Network);
+    #[test]
+    fn test_hrps() {
+        assert_eq!(Network::Main.sapling_hrp(), "zs");
+        assert_eq!(Network::Test.sapling_hrp(), "ztestsapling");
+        assert_eq!(Network::Main.unified_hrp(), "u");
+        assert_eq!(Network::Test.unified_hrp(), "utest");
+    }
+
+    #[test]
+    fn test_transparent_prefixes() {
+        assert_eq!(Network::Main.transparent_pubkey_prefix(), [0x1c, 0xb8]);
+        assert_eq!(Network::Test.transparent_pubkey_prefix(), [0x1d, 0x25]);
+        assert_eq!(Network::Main.transparent_script_prefix(), [0x1c, 0xbd]);
+        assert_eq!(Network::Test.transparent_script_prefix(), [0x1c, 0xba]);
+    }
+
+    #[test]
+    fn test_sapling_activation() {
+        assert_eq!(
+            Network::Main.activation_height(NetworkUpgrade::Sapling),
+            Some(BlockHeight::from_u32(419_200))
+        );
+        assert_eq!(
+            Network::Test.activation_height(NetworkUpgrade::Sapling),
+            Some(BlockHeight::from_u32(280_000))
+        );
+        assert_eq!(
+            Network::Regtest.activation_height(NetworkUpgrade::Sapling),
+            None
+        );
+    }
 }
@@ -0,0 +1,26 @@
+/// A Zcash network upgrade that changed the consensus rules at a fixed activation height.
+///
+/// Each upgrade introduced new transaction formats or protocol features, so knowing which
+/// upgrade was active at a given height is needed to interpret the transactions mined there.
+/// Activation heights are looked up with
+/// [`Network::activation_height`](crate::Network::activation_height).
+///
+/// The variants are listed in activation order, so comparing two upgrades compares when they
+/// activated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NetworkUpgrade {
+    /// Introduced v3 transactions with expiry heights (ZIP 202).
+    Overwinter,
+    /// Introduced the Sapling shielded protocol and v4 transactions.
+    Sapling,
+    /// Reduced the target block time to 75 seconds (ZIP 208).
+    Blossom,
+    /// Introduced shielded coinbase and FlyClient support.
+    Heartwood,
+    /// Introduced the development fund and ZIP 212 note plaintexts.
+    Canopy,
+    /// Introduced the Orchard shielded protocol, Unified Addresses and v5 transactions.
+    Nu5,
+    /// Introduced the lockbox funding stream.
+    Nu6,
+}
@@ -24,7 +24,7 @@ use bc_envelope::prelude::*;
 /// let value: u32 = index.into();
 /// assert_eq!(value, 42);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct NonHardenedChildIndex(u32);
 
 /// Converts a u32 value to a NonHardenedChildIndex
@@ -83,18 +83,19 @@ impl TryFrom<Envelope> for NonHardenedChildIndex {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for NonHardenedChildIndex {
+    fn random() -> Self {
+        Self(u32::random())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{test_cbor_roundtrip, test_envelope_roundtrip};
 
     use super::NonHardenedChildIndex;
 
-    impl crate::RandomInstance for NonHardenedChildIndex {
-        fn random() -> Self {
-            Self(u32::random())
-        }
-    }
-
     test_cbor_roundtrip!(NonHardenedChildIndex);
     test_envelope_roundtrip!(NonHardenedChildIndex);
 }
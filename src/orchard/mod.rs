@@ -1,3 +1,9 @@
+//! Zcash Orchard wallet data.
+//!
+//! There is no Orchard spending key type: Orchard keys are derived from the wallet's
+//! [`SeedMaterial`](crate::SeedMaterial) according to ZIP 32, and that seed material is what
+//! is wiped from memory on drop.
+
 use crate::mod_use;
 
 mod_use!(orchard_sent_output);
@@ -1,7 +1,7 @@
 use anyhow::Context;
 use bc_envelope::prelude::*;
 
-use crate::{Amount, Indexed, Memo};
+use crate::{Amount, Indexed, Memo, has_unified_hrp};
 
 /// Represents a sent output in an Orchard shielded transaction within a Zcash wallet.
 ///
@@ -144,6 +144,16 @@ impl OrchardSentOutput {
         self.recipient_address = recipient_address;
     }
 
+    /// Returns `true` if the recipient address is a Unified Address.
+    ///
+    /// Orchard receivers can only be addressed through a Unified Address, so this is expected
+    /// to hold for well-formed data; it is provided to parallel
+    /// [`SaplingSentOutput::recipient_is_unified`](crate::sapling::SaplingSentOutput::recipient_is_unified).
+    /// The check is based on the address's human-readable part (`u`, `utest` or `uregtest`).
+    pub fn recipient_is_unified(&self) -> bool {
+        has_unified_hrp(&self.recipient_address)
+    }
+
     /// Returns the value (amount) of ZEC sent in this output.
     ///
     /// This represents the amount of ZEC transferred in this specific note,
@@ -245,22 +255,36 @@ impl TryFrom<Envelope> for OrchardSentOutput {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for OrchardSentOutput {
+    fn random() -> Self {
+        Self {
+            index: 0,
+            recipient_address: UnifiedAddress::random().address().to_string(),
+            value: Amount::random(),
+            memo: Some(Memo::random()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Amount, Memo, UnifiedAddress, test_envelope_roundtrip};
+    use crate::{Amount, test_envelope_roundtrip};
 
     use super::OrchardSentOutput;
 
-    impl crate::RandomInstance for OrchardSentOutput {
-        fn random() -> Self {
-            Self {
-                index: 0,
-                recipient_address: UnifiedAddress::random().address().to_string(),
-                value: Amount::random(),
-                memo: Some(Memo::random()),
-            }
-        }
-    }
-
     test_envelope_roundtrip!(OrchardSentOutput);
+
+    const SAPLING_ADDRESS: &str =
+        "zs1z7rejlpsa98s2rrrfkwmaxu53e4ue0ulcrw0h4x5g8jl04tak0d3mm47vdtahatqrlkngh9sly";
+    const UNIFIED_ADDRESS: &str = "u1l8xunezsvhq8fgzfl7404m450nwnd76zshscn6nfys7vyz2ywyh4cc5daaq0c7q2su5lqfh23sp7fkf3kt27ve5948mzpfdvckzaect2jtte308mkwlycj2u0eac077wu70vqcetkxf";
+
+    #[test]
+    fn test_recipient_is_unified() {
+        let mut output =
+            OrchardSentOutput::from_parts(0, UNIFIED_ADDRESS.to_string(), Amount::zero(), None);
+        assert!(output.recipient_is_unified());
+        output.set_recipient_address(SAPLING_ADDRESS.to_string());
+        assert!(!output.recipient_is_unified());
+    }
 }
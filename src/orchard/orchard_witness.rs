@@ -93,17 +93,18 @@ impl TryFrom<Envelope> for OrchardWitness {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for OrchardWitness {
+    fn random() -> Self {
+        Self(IncrementalWitness::random())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{IncrementalWitness, RandomInstance, test_envelope_roundtrip};
+    use crate::test_envelope_roundtrip;
 
     use super::OrchardWitness;
 
-    impl RandomInstance for OrchardWitness {
-        fn random() -> Self {
-            Self(IncrementalWitness::random())
-        }
-    }
-
     test_envelope_roundtrip!(OrchardWitness);
 }
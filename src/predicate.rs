@@ -0,0 +1,96 @@
+use anyhow::{Context, Result, bail};
+use bc_envelope::prelude::*;
+
+use crate::Indexed;
+
+/// An assertion predicate written as a compact [`KnownValue`].
+///
+/// Format version 1 wrote these predicates as strings (e.g. `"name"`). Format version 2
+/// writes the known-value form, a 32-bit integer that encodes in 5 bytes regardless of the name's
+/// length and cannot be misspelled.
+/// Readers accept both forms for as long as version 1 containers are supported; the string
+/// form may only be dropped together with a bump of the minimum accepted format version.
+/// An object carrying both forms of one predicate is treated like a repeated assertion.
+#[derive(Debug, Clone)]
+pub(crate) struct Predicate {
+    known_value: KnownValue,
+    legacy: &'static str,
+}
+
+/// The first value of the private range used for ZeWIF predicates (`"zew"` in ASCII, shifted
+/// left one byte). It fits in 32 bits, so each predicate is a 5-byte CBOR unsigned integer,
+/// and lies far above the values assigned by the Blockchain Commons registry.
+const BASE: u64 = 0x7a65_7700;
+
+impl Predicate {
+    const fn new(offset: u64, name: &'static str) -> Self {
+        Self {
+            known_value: KnownValue::new_with_static_name(BASE + offset, name),
+            legacy: name,
+        }
+    }
+
+    /// Returns the known value written for this predicate.
+    pub(crate) fn known_value(&self) -> KnownValue {
+        self.known_value.clone()
+    }
+
+    /// Returns the objects of every assertion using either form of this predicate.
+    pub(crate) fn objects(&self, envelope: &Envelope) -> Vec<Envelope> {
+        let mut objects = envelope.objects_for_predicate(self.known_value());
+        objects.extend(envelope.objects_for_predicate(self.legacy));
+        objects
+    }
+
+    /// Extracts the object of the single assertion with this predicate, or `None` if
+    /// there is none.
+    pub(crate) fn extract_optional_object<T>(&self, envelope: &Envelope) -> Result<Option<T>>
+    where
+        T: TryFrom<CBOR, Error = dcbor::Error> + 'static,
+    {
+        let mut objects = self.objects(envelope);
+        if objects.len() > 1 {
+            bail!("Multiple \"{}\" assertions", self.legacy);
+        }
+        objects.pop().map(|o| o.extract_subject()).transpose()
+    }
+
+    /// Extracts the object of the single assertion with this predicate.
+    pub(crate) fn extract_object<T>(&self, envelope: &Envelope) -> Result<T>
+    where
+        T: TryFrom<CBOR, Error = dcbor::Error> + 'static,
+    {
+        self.extract_optional_object(envelope)?
+            .with_context(|| format!("Missing \"{}\" assertion", self.legacy))
+    }
+
+    /// Decodes the objects of every assertion with this predicate.
+    pub(crate) fn try_objects<T>(&self, envelope: &Envelope) -> Result<Vec<T>>
+    where
+        T: TryFrom<Envelope, Error = anyhow::Error> + 'static,
+    {
+        self.objects(envelope)
+            .into_iter()
+            .map(|o| o.try_as::<T>())
+            .collect()
+    }
+
+    /// Decodes the objects of every assertion with this predicate, sorted by their index.
+    pub(crate) fn try_indexed_objects<T>(&self, envelope: &Envelope) -> Result<Vec<T>>
+    where
+        T: Indexed + TryFrom<Envelope, Error = anyhow::Error> + 'static,
+    {
+        let mut objects: Vec<T> = self.try_objects(envelope)?;
+        objects.sort_by_key(|object| object.index());
+        Ok(objects)
+    }
+}
+
+pub(crate) const NAME: Predicate = Predicate::new(0, "name");
+pub(crate) const BIRTHDAY_HEIGHT: Predicate = Predicate::new(1, "birthday_height");
+pub(crate) const BIRTHDAY_BLOCK: Predicate = Predicate::new(2, "birthday_block");
+pub(crate) const ZIP32_ACCOUNT_ID: Predicate = Predicate::new(3, "zip32_account_id");
+pub(crate) const RELEVANT_TRANSACTIONS: Predicate = Predicate::new(4, "relevant_transactions");
+pub(crate) const ADDRESS: Predicate = Predicate::new(5, "address");
+pub(crate) const SAPLING_SENT_OUTPUT: Predicate = Predicate::new(6, "sapling_sent_output");
+pub(crate) const ORCHARD_SENT_OUTPUT: Predicate = Predicate::new(7, "orchard_sent_output");
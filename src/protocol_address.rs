@@ -1,4 +1,4 @@
-use crate::{UnifiedAddress, sapling, transparent};
+use crate::{Network, UnifiedAddress, sapling, transparent};
 use bc_envelope::prelude::*;
 
 /// A protocol-specific Zcash address representation without additional metadata.
@@ -164,6 +164,50 @@ impl ProtocolAddress {
     pub fn is_unified(&self) -> bool {
         matches!(self, ProtocolAddress::Unified(_))
     }
+
+    /// Infers the network this address belongs to from its encoding.
+    ///
+    /// Transparent addresses are identified by their Base58Check version prefix, and Sapling
+    /// and unified addresses by their Bech32 or Bech32m human-readable part. Only the prefix
+    /// is inspected; the rest of the encoding is not validated.
+    ///
+    /// Returns `None` if the address cannot be decoded or its prefix is unrecognized, and also
+    /// for transparent testnet addresses, whose prefixes are shared with regtest.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::{Network, ProtocolAddress, transparent};
+    /// let t_addr = transparent::Address::new("t1Hxw6JqWMnhDK5jRCieg5bFHM2qt7UtQvu");
+    /// let address = ProtocolAddress::Transparent(t_addr);
+    /// assert_eq!(address.network(), Some(Network::Main));
+    /// ```
+    pub fn network(&self) -> Option<Network> {
+        match self {
+            ProtocolAddress::Transparent(addr) => {
+                let prefix = addr.version_prefix().ok()?;
+                let main = Network::Main;
+                if prefix == main.transparent_pubkey_prefix()
+                    || prefix == main.transparent_script_prefix()
+                {
+                    Some(main)
+                } else {
+                    None
+                }
+            }
+            ProtocolAddress::Sapling(addr) => network_for_hrp(addr.address(), Network::sapling_hrp),
+            ProtocolAddress::Unified(addr) => network_for_hrp(addr.address(), Network::unified_hrp),
+        }
+    }
+}
+
+/// Finds the network whose human-readable part, as given by `hrp_of`, prefixes the Bech32 or
+/// Bech32m encoded `address`.
+fn network_for_hrp(address: &str, hrp_of: fn(&Network) -> &'static str) -> Option<Network> {
+    let separator = address.rfind('1')?;
+    let hrp = &address[..separator];
+    [Network::Main, Network::Test, Network::Regtest]
+        .into_iter()
+        .find(|network| hrp.eq_ignore_ascii_case(hrp_of(network)))
 }
 
 impl From<ProtocolAddress> for Envelope {
@@ -192,22 +236,67 @@ impl TryFrom<Envelope> for ProtocolAddress {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for ProtocolAddress {
+    fn random() -> Self {
+        let mut rng = rand::thread_rng();
+        let choice = rand::Rng::gen_range(&mut rng, 0..3);
+        match choice {
+            0 => ProtocolAddress::Transparent(transparent::Address::random()),
+            1 => ProtocolAddress::Sapling(Box::new(sapling::Address::random())),
+            _ => ProtocolAddress::Unified(Box::new(UnifiedAddress::random())),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::ProtocolAddress;
-    use crate::{UnifiedAddress, sapling, test_envelope_roundtrip, transparent};
-
-    impl crate::RandomInstance for ProtocolAddress {
-        fn random() -> Self {
-            let mut rng = rand::thread_rng();
-            let choice = rand::Rng::gen_range(&mut rng, 0..3);
-            match choice {
-                0 => ProtocolAddress::Transparent(transparent::Address::random()),
-                1 => ProtocolAddress::Sapling(Box::new(sapling::Address::random())),
-                _ => ProtocolAddress::Unified(Box::new(UnifiedAddress::random())),
-            }
-        }
-    }
+    use crate::{Network, UnifiedAddress, sapling, test_envelope_roundtrip, transparent};
 
     test_envelope_roundtrip!(ProtocolAddress);
+
+    fn t_addr(s: &str) -> ProtocolAddress {
+        ProtocolAddress::Transparent(transparent::Address::new(s))
+    }
+
+    fn z_addr(s: &str) -> ProtocolAddress {
+        ProtocolAddress::Sapling(Box::new(sapling::Address::new(s.to_string())))
+    }
+
+    fn u_addr(s: &str) -> ProtocolAddress {
+        ProtocolAddress::Unified(Box::new(UnifiedAddress::new(s.to_string())))
+    }
+
+    #[test]
+    fn test_network() {
+        assert_eq!(
+            t_addr("t1Hxw6JqWMnhDK5jRCieg5bFHM2qt7UtQvu").network(),
+            Some(Network::Main)
+        );
+        assert_eq!(
+            t_addr("t3Jex1rKwuh1bQFRrKpKGWDcDVZ8bbQuNrB").network(),
+            Some(Network::Main)
+        );
+        // Testnet and regtest share transparent prefixes.
+        assert_eq!(
+            t_addr("tm9ogR9KukTCiTKvrsSxQwFv2x1vhZTydav").network(),
+            None
+        );
+
+        assert_eq!(z_addr("zs1qqqqqqqq").network(), Some(Network::Main));
+        assert_eq!(
+            z_addr("ztestsapling1qqqqqqqq").network(),
+            Some(Network::Test)
+        );
+        assert_eq!(
+            z_addr("zregtestsapling1qqqqqqqq").network(),
+            Some(Network::Regtest)
+        );
+        assert_eq!(u_addr("utest1qqqqqqqq").network(), Some(Network::Test));
+
+        assert_eq!(t_addr("not an address").network(), None);
+        assert_eq!(z_addr("zs").network(), None);
+        assert_eq!(u_addr("zs1qqqqqqqq").network(), None);
+    }
 }
@@ -1,5 +1,5 @@
 use super::{SaplingExtendedFullViewingKey, SaplingExtendedSpendingKey, SaplingIncomingViewingKey};
-use crate::{Blob, NoQuotesDebugOption, test_envelope_roundtrip};
+use crate::{Blob, DerivationInfo, NoQuotesDebugOption, test_envelope_roundtrip};
 
 use anyhow::Context;
 use bc_envelope::prelude::*;
@@ -79,6 +79,11 @@ pub struct Address {
     /// Preserving this information allows wallets to reconstruct their address hierarchy.
     hd_derivation_path: Option<String>,
 
+    /// The structured form of the non-hardened tail of `hd_derivation_path`, if known.
+    ///
+    /// The path string is retained alongside this for round-trip fidelity.
+    derivation_info: Option<DerivationInfo>,
+
     /// The diversifier index used creating this address, if known, stored as a byte array in
     /// little-endian order.
     diversifier_index: Option<Blob<11>>,
@@ -95,6 +100,7 @@ impl std::fmt::Debug for Address {
             .field("spending_key", &self.spending_key)
             .field("diversifier_index", &self.diversifier_index)
             .field("hd_derivation_path", &self.hd_derivation_path)
+            .field("derivation_info", &self.derivation_info)
             .finish()
     }
 }
@@ -108,6 +114,7 @@ impl Address {
             spending_key: None,
             diversifier_index: None,
             hd_derivation_path: None,
+            derivation_info: None,
         }
     }
 
@@ -173,9 +180,24 @@ impl Address {
     }
 
     /// Set the HD derivation path for this address
+    ///
+    /// The structured [`derivation_info`](Self::derivation_info) is replaced by the one
+    /// parsed from the path, or cleared if the path does not end in two non-hardened
+    /// components, so that the two forms never disagree.
     pub fn set_hd_derivation_path(&mut self, path: String) {
+        self.derivation_info = DerivationInfo::from_path_string(&path).ok();
         self.hd_derivation_path = Some(path);
     }
+
+    /// Get the structured HD derivation information for this address, if available
+    pub fn derivation_info(&self) -> Option<&DerivationInfo> {
+        self.derivation_info.as_ref()
+    }
+
+    /// Set the structured HD derivation information for this address
+    pub fn set_derivation_info(&mut self, derivation_info: DerivationInfo) {
+        self.derivation_info = Some(derivation_info);
+    }
 }
 
 impl From<Address> for Envelope {
@@ -187,6 +209,7 @@ impl From<Address> for Envelope {
             .add_optional_assertion("spending_key", value.spending_key)
             .add_optional_assertion("diversifier_index", value.diversifier_index)
             .add_optional_assertion("hd_derivation_path", value.hd_derivation_path)
+            .add_optional_assertion("derivation_info", value.derivation_info)
     }
 }
 
@@ -213,6 +236,9 @@ impl TryFrom<Envelope> for Address {
         let hd_derivation_path = envelope
             .try_optional_object_for_predicate("hd_derivation_path")
             .context("hd_derivation_path")?;
+        let derivation_info = envelope
+            .try_optional_object_for_predicate("derivation_info")
+            .context("derivation_info")?;
         Ok(Address {
             address,
             incoming_viewing_key,
@@ -220,11 +246,12 @@ impl TryFrom<Envelope> for Address {
             spending_key,
             diversifier_index,
             hd_derivation_path,
+            derivation_info,
         })
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "test-dependencies"))]
 impl crate::RandomInstance for Address {
     fn random() -> Self {
         Self {
@@ -234,8 +261,35 @@ impl crate::RandomInstance for Address {
             spending_key: SaplingExtendedSpendingKey::opt_random(),
             diversifier_index: Blob::<11>::opt_random(),
             hd_derivation_path: String::opt_random(),
+            derivation_info: DerivationInfo::opt_random(),
         }
     }
 }
 
 test_envelope_roundtrip!(Address);
+
+#[test]
+fn test_derivation_info_roundtrip() {
+    let mut address = Address::new("zs1exampleaddress".to_string());
+    address.set_hd_derivation_path("m/32'/133'/0'/0/5".to_string());
+    assert_eq!(
+        address.derivation_info(),
+        Some(&DerivationInfo::new(0u32.into(), 5u32.into()))
+    );
+
+    let decoded = Address::try_from(Envelope::from(address.clone())).unwrap();
+    assert_eq!(decoded.hd_derivation_path(), Some("m/32'/133'/0'/0/5"));
+    assert_eq!(decoded.derivation_info(), address.derivation_info());
+    assert_eq!(decoded, address);
+}
+
+#[test]
+fn test_unparseable_path_clears_derivation_info() {
+    let mut address = Address::new("zs1exampleaddress".to_string());
+    address.set_hd_derivation_path("m/32'/133'/0'/0/5".to_string());
+    assert!(address.derivation_info().is_some());
+
+    address.set_hd_derivation_path("m/32'/133'/0'".to_string());
+    assert_eq!(address.hd_derivation_path(), Some("m/32'/133'/0'"));
+    assert_eq!(address.derivation_info(), None);
+}
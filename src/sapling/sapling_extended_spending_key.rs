@@ -16,3 +16,10 @@ blob!(
 );
 
 blob_envelope!(SaplingExtendedSpendingKey);
+
+/// The key bytes are overwritten with zeros when the key is dropped.
+impl Drop for SaplingExtendedSpendingKey {
+    fn drop(&mut self) {
+        bc_crypto::memzero(&mut self.0);
+    }
+}
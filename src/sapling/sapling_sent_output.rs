@@ -1,7 +1,7 @@
 use anyhow::Context;
 use bc_envelope::prelude::*;
 
-use crate::{Amount, Indexed, Memo};
+use crate::{Amount, Indexed, Memo, has_unified_hrp};
 
 /// Represents a sent output in a Sapling shielded transaction within a Zcash wallet.
 ///
@@ -137,6 +137,14 @@ impl SaplingSentOutput {
         self.recipient_address = recipient_address;
     }
 
+    /// Returns `true` if the recipient address is a Unified Address rather than a bare Sapling
+    /// address.
+    ///
+    /// The check is based on the address's human-readable part (`u`, `utest` or `uregtest`).
+    pub fn recipient_is_unified(&self) -> bool {
+        has_unified_hrp(&self.recipient_address)
+    }
+
     /// Returns the value (amount) of ZEC sent in this output.
     ///
     /// This represents the amount of ZEC transferred in this specific note,
@@ -237,21 +245,42 @@ impl TryFrom<Envelope> for SaplingSentOutput {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for SaplingSentOutput {
+    fn random() -> Self {
+        Self {
+            index: 0,
+            recipient_address: String::random(),
+            value: Amount::random(),
+            memo: Some(Memo::random()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::SaplingSentOutput;
-    use crate::{Amount, Memo, test_envelope_roundtrip};
-
-    impl crate::RandomInstance for SaplingSentOutput {
-        fn random() -> Self {
-            Self {
-                index: 0,
-                recipient_address: String::random(),
-                value: Amount::random(),
-                memo: Some(Memo::random()),
-            }
-        }
-    }
+    use crate::test_envelope_roundtrip;
 
     test_envelope_roundtrip!(SaplingSentOutput);
+
+    const SAPLING_ADDRESS: &str =
+        "zs1z7rejlpsa98s2rrrfkwmaxu53e4ue0ulcrw0h4x5g8jl04tak0d3mm47vdtahatqrlkngh9sly";
+    const UNIFIED_ADDRESS: &str = "u1l8xunezsvhq8fgzfl7404m450nwnd76zshscn6nfys7vyz2ywyh4cc5daaq0c7q2su5lqfh23sp7fkf3kt27ve5948mzpfdvckzaect2jtte308mkwlycj2u0eac077wu70vqcetkxf";
+
+    #[test]
+    fn test_recipient_is_unified() {
+        let mut output = SaplingSentOutput::new();
+        output.set_recipient_address(SAPLING_ADDRESS.to_string());
+        assert!(!output.recipient_is_unified());
+        output.set_recipient_address(UNIFIED_ADDRESS.to_string());
+        assert!(output.recipient_is_unified());
+
+        output.set_recipient_address("ztestsapling1qqqqqqqqqqqqqqqqqqq".to_string());
+        assert!(!output.recipient_is_unified());
+        output.set_recipient_address("utest1qqqqqqqqqqqqqqqqqqq".to_string());
+        assert!(output.recipient_is_unified());
+        output.set_recipient_address(String::new());
+        assert!(!output.recipient_is_unified());
+    }
 }
@@ -93,17 +93,18 @@ impl TryFrom<Envelope> for SaplingWitness {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for SaplingWitness {
+    fn random() -> Self {
+        Self(IncrementalWitness::random())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{IncrementalWitness, RandomInstance, test_envelope_roundtrip};
+    use crate::test_envelope_roundtrip;
 
     use super::SaplingWitness;
 
-    impl RandomInstance for SaplingWitness {
-        fn random() -> Self {
-            Self(IncrementalWitness::random())
-        }
-    }
-
     test_envelope_roundtrip!(SaplingWitness);
 }
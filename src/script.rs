@@ -1,4 +1,4 @@
-use super::Data;
+use super::{Data, Op, ScriptBuilder, compact_size_len};
 use anyhow::{Context, Result};
 use bc_envelope::prelude::*;
 use std::ops::{
@@ -52,6 +52,87 @@ impl Script {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Returns the number of bytes this script occupies in a serialized transaction: its
+    /// CompactSize length prefix followed by the script bytes.
+    pub fn serialized_size(&self) -> usize {
+        compact_size_len(self.len()) + self.len()
+    }
+
+    /// Returns a [`ScriptBuilder`] for assembling a script from opcodes and data pushes.
+    pub fn builder() -> ScriptBuilder {
+        ScriptBuilder::new()
+    }
+
+    /// Iterates over the operations in the script, decoding data pushes.
+    ///
+    /// Each item is a decoded [`Op`]. `OP_0` is yielded as an empty [`Op::Push`], matching
+    /// what [`ScriptBuilder::push_slice`] emits for an empty slice. If a push is truncated,
+    /// the iterator yields an error and then stops.
+    pub fn iter_ops(&self) -> impl Iterator<Item = Result<Op>> + '_ {
+        let mut remaining = &self.0[..];
+        std::iter::from_fn(move || {
+            if remaining.is_empty() {
+                return None;
+            }
+            match Op::decode(remaining) {
+                Ok((op, size)) => {
+                    remaining = &remaining[size..];
+                    Some(Ok(op))
+                }
+                Err(e) => {
+                    remaining = &[];
+                    Some(Err(e))
+                }
+            }
+        })
+    }
+
+    /// Returns `true` if the script consists only of data pushes.
+    ///
+    /// As in Bitcoin's `IsPushOnly`, the small-integer opcodes `OP_0`, `OP_1NEGATE` and
+    /// `OP_1` through `OP_16` count as pushes. A script with a truncated push is not
+    /// push-only.
+    pub fn is_push_only(&self) -> bool {
+        self.iter_ops().all(|op| match op {
+            Ok(Op::Push(_)) => true,
+            Ok(Op::Opcode(opcode)) => opcode <= Op::OP_16,
+            Err(_) => false,
+        })
+    }
+
+    /// Compares two scripts by their decoded operations rather than their bytes.
+    ///
+    /// Pushes of the same data compare equal whichever push encoding they use, including
+    /// `OP_0` as a push of no bytes. `==` remains a byte-exact comparison. If either
+    /// script has a truncated push, the scripts are compared byte for byte.
+    pub fn semantic_eq(&self, other: &Script) -> bool {
+        let ops = |script: &Script| script.iter_ops().collect::<Result<Vec<Op>>>();
+        match (ops(self), ops(other)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => self == other,
+        }
+    }
+
+    /// Returns the data payload if this is an `OP_RETURN` (null data) script.
+    ///
+    /// The script must consist of `OP_RETURN` followed by nothing, `OP_0`, or a single data
+    /// push; the payload is the pushed bytes without their length prefix (empty in the first
+    /// two cases). Any other script, including an `OP_RETURN` followed by several pushes or
+    /// non-push opcodes, returns `None`.
+    pub fn is_op_return(&self) -> Option<&[u8]> {
+        let (&first, rest) = self.0[..].split_first()?;
+        if first != Op::OP_RETURN {
+            return None;
+        }
+        if rest.is_empty() {
+            return Some(rest);
+        }
+        match Op::decode(rest).ok()? {
+            (Op::Push(data), size) if size == rest.len() => Some(&rest[size - data.len()..]),
+            _ => None,
+        }
+    }
 }
 
 /// Debug formatting that includes script length and hex representation
@@ -220,22 +301,206 @@ impl TryFrom<Envelope> for Script {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for Script {
+    fn random_with_size(size: usize) -> Self {
+        Self(Data::random_with_size(size))
+    }
+
+    fn random() -> Self {
+        Self(Data::random())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Data, test_cbor_roundtrip, test_envelope_roundtrip};
+    use crate::{Data, Op, test_cbor_roundtrip, test_envelope_roundtrip};
 
     use super::Script;
 
-    impl crate::RandomInstance for Script {
-        fn random_with_size(size: usize) -> Self {
-            Self(Data::random_with_size(size))
-        }
+    test_cbor_roundtrip!(Script);
+    test_envelope_roundtrip!(Script);
 
-        fn random() -> Self {
-            Self(Data::random())
-        }
+    #[test]
+    fn test_builder_p2pkh() {
+        let pubkey_hash = [0x42u8; 20];
+        let script = Script::builder()
+            .push_opcode(Op::OP_DUP)
+            .push_opcode(Op::OP_HASH160)
+            .push_slice(&pubkey_hash)
+            .push_opcode(Op::OP_EQUALVERIFY)
+            .push_opcode(Op::OP_CHECKSIG)
+            .build();
+
+        let mut expected = vec![0x76, 0xa9, 0x14];
+        expected.extend_from_slice(&pubkey_hash);
+        expected.extend_from_slice(&[0x88, 0xac]);
+        assert_eq!(script.as_ref(), &expected[..]);
+
+        let ops: Vec<Op> = script.iter_ops().collect::<anyhow::Result<_>>().unwrap();
+        assert_eq!(
+            ops,
+            vec![
+                Op::Opcode(Op::OP_DUP),
+                Op::Opcode(Op::OP_HASH160),
+                Op::Push(Data::from_slice(&pubkey_hash)),
+                Op::Opcode(Op::OP_EQUALVERIFY),
+                Op::Opcode(Op::OP_CHECKSIG),
+            ]
+        );
     }
 
-    test_cbor_roundtrip!(Script);
-    test_envelope_roundtrip!(Script);
+    #[test]
+    fn test_builder_empty_push_roundtrip() {
+        let script = Script::builder()
+            .push_slice(&[])
+            .push_opcode(Op::OP_0)
+            .push_opcode(Op::OP_CHECKSIG)
+            .build();
+        assert_eq!(script.as_ref(), &[Op::OP_0, Op::OP_0, Op::OP_CHECKSIG][..]);
+
+        let ops: Vec<Op> = script.iter_ops().collect::<anyhow::Result<_>>().unwrap();
+        assert_eq!(
+            ops,
+            vec![
+                Op::Push(Data::new()),
+                Op::Push(Data::new()),
+                Op::Opcode(Op::OP_CHECKSIG),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_builder_pushdata_prefixes() {
+        let short = vec![1u8; 0x4b];
+        let medium = vec![2u8; 0xff];
+        let long = vec![3u8; 0x100];
+        let script = Script::builder()
+            .push_slice(&short)
+            .push_slice(&medium)
+            .push_slice(&long)
+            .build();
+
+        assert_eq!(script[0], 0x4b);
+        assert_eq!(&script[0x4c..0x4e], &[Op::OP_PUSHDATA1, 0xff]);
+        let long_start = 0x4e + 0xff;
+        assert_eq!(
+            &script[long_start..long_start + 3],
+            &[Op::OP_PUSHDATA2, 0x00, 0x01]
+        );
+
+        let ops: Vec<Op> = script.iter_ops().collect::<anyhow::Result<_>>().unwrap();
+        assert_eq!(
+            ops,
+            vec![
+                Op::Push(Data::from_vec(short)),
+                Op::Push(Data::from_vec(medium)),
+                Op::Push(Data::from_vec(long)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_push_only() {
+        let script_sig = Script::builder()
+            .push_slice(&[0x30; 71])
+            .push_slice(&[0x02; 33])
+            .build();
+        assert!(script_sig.is_push_only());
+
+        let small_ints = Script::builder()
+            .push_opcode(Op::OP_0)
+            .push_opcode(Op::OP_1NEGATE)
+            .push_opcode(Op::OP_16)
+            .build();
+        assert!(small_ints.is_push_only());
+
+        let with_checksig = Script::builder()
+            .push_slice(&[0x02; 33])
+            .push_opcode(Op::OP_CHECKSIG)
+            .build();
+        assert!(!with_checksig.is_push_only());
+
+        let truncated = Script::from(Data::from_vec(vec![0x14, 0x00]));
+        assert!(!truncated.is_push_only());
+    }
+
+    #[test]
+    fn test_is_op_return() {
+        let payload = b"memo in a transparent output";
+        let null_data = Script::builder()
+            .push_opcode(Op::OP_RETURN)
+            .push_slice(payload)
+            .build();
+        assert_eq!(null_data.is_op_return(), Some(&payload[..]));
+
+        let bare = Script::builder().push_opcode(Op::OP_RETURN).build();
+        assert_eq!(bare.is_op_return(), Some(&[][..]));
+
+        let two_pushes = Script::builder()
+            .push_opcode(Op::OP_RETURN)
+            .push_slice(&[1])
+            .push_slice(&[2])
+            .build();
+        assert_eq!(two_pushes.is_op_return(), None);
+
+        let p2pkh = Script::builder()
+            .push_opcode(Op::OP_DUP)
+            .push_opcode(Op::OP_HASH160)
+            .push_slice(&[0x42; 20])
+            .push_opcode(Op::OP_EQUALVERIFY)
+            .push_opcode(Op::OP_CHECKSIG)
+            .build();
+        assert_eq!(p2pkh.is_op_return(), None);
+    }
+
+    #[test]
+    fn test_semantic_eq() {
+        let data = [0xab; 20];
+        let minimal = Script::builder()
+            .push_opcode(Op::OP_DUP)
+            .push_slice(&data)
+            .build();
+        let mut pushdata1 = vec![Op::OP_DUP, Op::OP_PUSHDATA1, 20];
+        pushdata1.extend_from_slice(&data);
+        let pushdata1 = Script::from(Data::from_vec(pushdata1));
+        let mut pushdata2 = vec![Op::OP_DUP, Op::OP_PUSHDATA2, 20, 0];
+        pushdata2.extend_from_slice(&data);
+        let pushdata2 = Script::from(Data::from_vec(pushdata2));
+
+        assert_ne!(minimal, pushdata1);
+        assert!(minimal.semantic_eq(&pushdata1));
+        assert!(pushdata1.semantic_eq(&pushdata2));
+
+        let empty_push = Script::from(Data::from_vec(vec![Op::OP_PUSHDATA1, 0]));
+        let op_0 = Script::builder().push_opcode(Op::OP_0).build();
+        assert!(empty_push.semantic_eq(&op_0));
+
+        let other = Script::builder()
+            .push_opcode(Op::OP_DUP)
+            .push_slice(&[0xcd; 20])
+            .build();
+        assert!(!minimal.semantic_eq(&other));
+
+        let truncated = Script::from(Data::from_vec(vec![0x14, 0x00]));
+        assert!(truncated.semantic_eq(&truncated.clone()));
+        assert!(!truncated.semantic_eq(&minimal));
+    }
+
+    #[test]
+    fn test_iter_ops_truncated_push() {
+        let script = Script::from(Data::from_vec(vec![Op::OP_DUP, 0x14, 0x00, 0x01]));
+        let mut ops = script.iter_ops();
+        assert_eq!(ops.next().unwrap().unwrap(), Op::Opcode(Op::OP_DUP));
+        assert!(ops.next().unwrap().is_err());
+        assert!(ops.next().is_none());
+    }
+
+    #[test]
+    fn test_serialized_size() {
+        let script = |len: usize| Script::from(Data::from_vec(vec![Op::OP_RETURN; len]));
+        assert_eq!(script(0).serialized_size(), 1);
+        assert_eq!(script(252).serialized_size(), 253);
+        assert_eq!(script(253).serialized_size(), 256);
+    }
 }
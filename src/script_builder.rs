@@ -0,0 +1,71 @@
+use crate::{Data, Op, Script};
+
+/// An incremental builder for [`Script`] values.
+///
+/// `ScriptBuilder` appends opcodes and data pushes in order, choosing the minimal push
+/// encoding for each slice, and produces the finished script with [`build`](Self::build).
+/// It is mainly useful for constructing scripts in tests and tools without assembling
+/// raw byte vectors by hand.
+///
+/// # Examples
+/// ```
+/// # use zewif::{Op, Script};
+/// let pubkey_hash = [0x11; 20];
+/// let script = Script::builder()
+///     .push_opcode(Op::OP_DUP)
+///     .push_opcode(Op::OP_HASH160)
+///     .push_slice(&pubkey_hash)
+///     .push_opcode(Op::OP_EQUALVERIFY)
+///     .push_opcode(Op::OP_CHECKSIG)
+///     .build();
+/// assert_eq!(script.len(), 25);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ScriptBuilder(Vec<u8>);
+
+impl ScriptBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a single opcode byte.
+    pub fn push_opcode(mut self, opcode: u8) -> Self {
+        self.0.push(opcode);
+        self
+    }
+
+    /// Appends a data push of `data`, prefixed with the minimal push encoding.
+    ///
+    /// An empty slice is encoded as `OP_0`, slices of up to 75 bytes use a direct length
+    /// byte, and longer slices use `OP_PUSHDATA1` or `OP_PUSHDATA2`. Each push is decoded by
+    /// [`Script::iter_ops`] as an [`Op::Push`] of the same bytes, including the empty one.
+    ///
+    /// # Panics
+    /// Panics if `data` is longer than `0xffff` bytes, the maximum size of a [`Script`].
+    pub fn push_slice(mut self, data: &[u8]) -> Self {
+        let len = data.len();
+        match len {
+            0 => self.0.push(Op::OP_0),
+            1..=0x4b => self.0.push(len as u8),
+            0x4c..=0xff => {
+                self.0.push(Op::OP_PUSHDATA1);
+                self.0.push(len as u8);
+            }
+            0x100..=0xffff => {
+                self.0.push(Op::OP_PUSHDATA2);
+                self.0.extend_from_slice(&(len as u16).to_le_bytes());
+            }
+            _ => panic!(
+                "Script push of {} bytes exceeds maximum size of 65535 bytes",
+                len
+            ),
+        }
+        self.0.extend_from_slice(data);
+        self
+    }
+
+    /// Consumes the builder and returns the assembled script.
+    pub fn build(self) -> Script {
+        Script::from(Data::from_vec(self.0))
+    }
+}
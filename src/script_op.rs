@@ -0,0 +1,85 @@
+use anyhow::{Result, bail};
+
+use crate::Data;
+
+/// A single decoded element of a [`Script`](crate::Script): either a bare opcode or a data push.
+///
+/// `Op` is produced by [`Script::iter_ops`](crate::Script::iter_ops) and is the unit that
+/// [`ScriptBuilder`](crate::ScriptBuilder) emits. Data pushes are normalized: the push prefix
+/// (a direct length byte or one of the `OP_PUSHDATA*` opcodes) is consumed, and only the pushed
+/// bytes are retained. `OP_0` pushes no bytes, so it is decoded as an empty [`Op::Push`].
+///
+/// # Zcash Concept Relation
+/// Zcash transparent scripts use the Bitcoin script encoding, in which opcodes `0x01..=0x4b`
+/// push that many following bytes, and `OP_PUSHDATA1`, `OP_PUSHDATA2`, and `OP_PUSHDATA4`
+/// prefix the pushed bytes with a 1-, 2-, or 4-byte little-endian length.
+///
+/// # Examples
+/// ```
+/// # use zewif::{Data, Op, Script};
+/// let script = Script::builder()
+///     .push_opcode(Op::OP_DUP)
+///     .push_slice(&[0xab; 3])
+///     .build();
+///
+/// let ops = script.iter_ops().collect::<anyhow::Result<Vec<_>>>().unwrap();
+/// assert_eq!(ops, vec![Op::Opcode(Op::OP_DUP), Op::Push(Data::from_slice(&[0xab; 3]))]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Op {
+    /// An opcode that does not push data.
+    Opcode(u8),
+    /// A data push, holding the pushed bytes without their length prefix.
+    Push(Data),
+}
+
+impl Op {
+    pub const OP_0: u8 = 0x00;
+    pub const OP_PUSHDATA1: u8 = 0x4c;
+    pub const OP_PUSHDATA2: u8 = 0x4d;
+    pub const OP_PUSHDATA4: u8 = 0x4e;
+    pub const OP_1NEGATE: u8 = 0x4f;
+    pub const OP_1: u8 = 0x51;
+    pub const OP_16: u8 = 0x60;
+    pub const OP_RETURN: u8 = 0x6a;
+    pub const OP_DUP: u8 = 0x76;
+    pub const OP_EQUAL: u8 = 0x87;
+    pub const OP_EQUALVERIFY: u8 = 0x88;
+    pub const OP_HASH160: u8 = 0xa9;
+    pub const OP_CHECKSIG: u8 = 0xac;
+
+    /// Decodes the operation at the start of `bytes`, returning it together with the number of
+    /// bytes it occupies.
+    pub(crate) fn decode(bytes: &[u8]) -> Result<(Op, usize)> {
+        let Some(&opcode) = bytes.first() else {
+            bail!("Cannot decode an operation from an empty script");
+        };
+        let rest = &bytes[1..];
+        let (len_size, push_len) = match opcode {
+            Self::OP_0..=0x4b => (0, opcode as usize),
+            Self::OP_PUSHDATA1 => (1, Self::read_len(rest, 1)?),
+            Self::OP_PUSHDATA2 => (2, Self::read_len(rest, 2)?),
+            Self::OP_PUSHDATA4 => (4, Self::read_len(rest, 4)?),
+            _ => return Ok((Op::Opcode(opcode), 1)),
+        };
+        let data = &rest[len_size..];
+        if data.len() < push_len {
+            bail!(
+                "Script push of {} bytes is truncated (only {} remaining)",
+                push_len,
+                data.len()
+            );
+        }
+        let op = Op::Push(Data::from_slice(&data[..push_len]));
+        Ok((op, 1 + len_size + push_len))
+    }
+
+    fn read_len(bytes: &[u8], size: usize) -> Result<usize> {
+        if bytes.len() < size {
+            bail!("Script push length prefix is truncated");
+        }
+        let mut buf = [0u8; 4];
+        buf[..size].copy_from_slice(&bytes[..size]);
+        Ok(u32::from_le_bytes(buf) as usize)
+    }
+}
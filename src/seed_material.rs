@@ -109,21 +109,22 @@ impl TryFrom<Envelope> for SeedMaterial {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for SeedMaterial {
+    fn random() -> Self {
+        if rand::random::<bool>() {
+            SeedMaterial::Bip39Mnemonic(Bip39Mnemonic::random())
+        } else {
+            SeedMaterial::LegacySeed(LegacySeed::random())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Bip39Mnemonic, LegacySeed, test_envelope_roundtrip};
+    use crate::test_envelope_roundtrip;
 
     use super::SeedMaterial;
 
-    impl crate::RandomInstance for SeedMaterial {
-        fn random() -> Self {
-            if rand::random::<bool>() {
-                SeedMaterial::Bip39Mnemonic(Bip39Mnemonic::random())
-            } else {
-                SeedMaterial::LegacySeed(LegacySeed::random())
-            }
-        }
-    }
-
     test_envelope_roundtrip!(SeedMaterial, 10, true);
 }
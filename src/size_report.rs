@@ -0,0 +1,41 @@
+/// A breakdown of a container's serialized size, produced by
+/// [`Zewif::size_report`](crate::Zewif::size_report).
+///
+/// Each size is the length in bytes of the item's envelope encoded as CBOR on its own. Items
+/// embedded in the full container are encoded slightly differently (without their outer tag,
+/// and alongside their predicate), so [`total_size`](Self::total_size) approximates rather
+/// than equals the size of the whole container.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SizeReport {
+    wallet_sizes: Vec<usize>,
+    transactions_size: usize,
+}
+
+impl SizeReport {
+    pub(crate) fn new(wallet_sizes: Vec<usize>, transactions_size: usize) -> Self {
+        Self {
+            wallet_sizes,
+            transactions_size,
+        }
+    }
+
+    /// The serialized size of each wallet, in wallet order.
+    pub fn wallet_sizes(&self) -> &[usize] {
+        &self.wallet_sizes
+    }
+
+    /// The combined serialized size of all wallets.
+    pub fn wallets_size(&self) -> usize {
+        self.wallet_sizes.iter().sum()
+    }
+
+    /// The combined serialized size of all transactions in the global transaction map.
+    pub fn transactions_size(&self) -> usize {
+        self.transactions_size
+    }
+
+    /// The combined serialized size of all wallets and transactions.
+    pub fn total_size(&self) -> usize {
+        self.wallets_size() + self.transactions_size
+    }
+}
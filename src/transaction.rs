@@ -1,6 +1,6 @@
-use super::{BlockHeight, Data, TxId};
+use super::{Amount, BlockHeight, Data, TxId};
 use crate::TxBlockPosition;
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use bc_envelope::prelude::*;
 
 /// A Zcash transaction that can combine transparent and multiple shielded protocol components.
@@ -56,6 +56,19 @@ pub struct Transaction {
     txid: TxId,
     /// The raw transaction data, if known.
     raw: Option<Data>,
+    /// The transaction format version, if known.
+    version: Option<u32>,
+    /// The version group ID, present for v3 (Overwinter) and later transactions, if known.
+    version_group_id: Option<u32>,
+    /// The height after which the transaction can no longer be mined, if known.
+    /// An expiry height of zero means the transaction does not expire.
+    expiry_height: Option<BlockHeight>,
+    /// The raw `nLockTime` field, if known. See [`LockTime`](crate::LockTime) for its interpretation.
+    lock_time: Option<u32>,
+    /// The net value leaving the Sapling pool (`valueBalanceSapling`), if known.
+    sapling_value_balance: Option<Amount>,
+    /// The net value leaving the Orchard pool (`valueBalanceOrchard`), if known.
+    orchard_value_balance: Option<Amount>,
     /// The height for which the transaction was constructed, which implies
     /// the consensus branch for which the transaction was intended, if known.
     target_height: Option<BlockHeight>,
@@ -78,6 +91,12 @@ impl Transaction {
         Self {
             txid,
             raw: None,
+            version: None,
+            version_group_id: None,
+            expiry_height: None,
+            lock_time: None,
+            sapling_value_balance: None,
+            orchard_value_balance: None,
             target_height: None,
             mined_height: None,
             block_position: None,
@@ -101,6 +120,62 @@ impl Transaction {
         self.raw = Some(raw);
     }
 
+    pub fn version(&self) -> Option<u32> {
+        self.version
+    }
+
+    pub fn set_version(&mut self, version: u32) {
+        self.version = Some(version);
+    }
+
+    pub fn version_group_id(&self) -> Option<u32> {
+        self.version_group_id
+    }
+
+    pub fn set_version_group_id(&mut self, version_group_id: u32) {
+        self.version_group_id = Some(version_group_id);
+    }
+
+    pub fn expiry_height(&self) -> Option<&BlockHeight> {
+        self.expiry_height.as_ref()
+    }
+
+    pub fn set_expiry_height(&mut self, height: BlockHeight) {
+        self.expiry_height = Some(height);
+    }
+
+    pub fn lock_time(&self) -> Option<u32> {
+        self.lock_time
+    }
+
+    pub fn set_lock_time(&mut self, lock_time: u32) {
+        self.lock_time = Some(lock_time);
+    }
+
+    /// The net value flowing out of the Sapling shielded pool in this transaction, if known.
+    ///
+    /// A positive balance means value left the Sapling pool (to transparent outputs, fees, or
+    /// another pool); a negative balance means value entered it.
+    pub fn sapling_value_balance(&self) -> Option<Amount> {
+        self.sapling_value_balance
+    }
+
+    pub fn set_sapling_value_balance(&mut self, value_balance: Amount) {
+        self.sapling_value_balance = Some(value_balance);
+    }
+
+    /// The net value flowing out of the Orchard shielded pool in this transaction, if known.
+    ///
+    /// A positive balance means value left the Orchard pool (to transparent outputs, fees, or
+    /// another pool); a negative balance means value entered it.
+    pub fn orchard_value_balance(&self) -> Option<Amount> {
+        self.orchard_value_balance
+    }
+
+    pub fn set_orchard_value_balance(&mut self, value_balance: Amount) {
+        self.orchard_value_balance = Some(value_balance);
+    }
+
     pub fn target_height(&self) -> Option<&BlockHeight> {
         self.target_height.as_ref()
     }
@@ -124,6 +199,46 @@ impl Transaction {
     pub fn set_block_position(&mut self, block_position: Option<TxBlockPosition>) {
         self.block_position = block_position;
     }
+
+    /// Computes the transaction ID from the raw consensus serialization.
+    ///
+    /// For transaction versions 1 and 2 the txid is the double SHA-256 of the serialized
+    /// transaction. The result is in internal byte order; its [`Display`](std::fmt::Display)
+    /// form is the byte-reversed hex shown by block explorers, so it can be compared directly
+    /// with [`txid`](Self::txid).
+    ///
+    /// # Errors
+    /// Returns an error if the raw transaction data is not known, if its version header does
+    /// not match [`version`](Self::version), or if it is an Overwinter-or-later (v3+)
+    /// transaction, for which txid computation is not yet implemented.
+    pub fn compute_txid(&self) -> Result<TxId> {
+        let raw = self
+            .raw
+            .as_ref()
+            .context("Cannot compute txid without raw transaction data")?;
+        let bytes: &[u8] = raw.as_ref();
+        let header: [u8; 4] = bytes
+            .get(..4)
+            .and_then(|header| header.try_into().ok())
+            .context("Raw transaction is too short to contain a version header")?;
+        let header = u32::from_le_bytes(header);
+        let overwintered = header & 0x8000_0000 != 0;
+        let version = header & 0x7fff_ffff;
+        if let Some(expected) = self.version.filter(|&expected| expected != version) {
+            bail!(
+                "Raw transaction has version {} but the transaction records version {}",
+                version,
+                expected
+            );
+        }
+        if overwintered || !(1..=2).contains(&version) {
+            bail!(
+                "Computing the txid of a version {} transaction is not supported",
+                version
+            );
+        }
+        Ok(TxId::from_bytes(bc_crypto::double_sha256(bytes)))
+    }
 }
 
 #[rustfmt::skip]
@@ -132,6 +247,12 @@ impl From<Transaction> for Envelope {
         let e = Envelope::new(value.txid)
             .add_type("Transaction")
             .add_optional_assertion("raw", value.raw)
+            .add_optional_assertion("version", value.version)
+            .add_optional_assertion("version_group_id", value.version_group_id)
+            .add_optional_assertion("expiry_height", value.expiry_height)
+            .add_optional_assertion("lock_time", value.lock_time)
+            .add_optional_assertion("sapling_value_balance", value.sapling_value_balance)
+            .add_optional_assertion("orchard_value_balance", value.orchard_value_balance)
             .add_optional_assertion("target_height", value.target_height)
             .add_optional_assertion("mined_height", value.mined_height)
             .add_optional_assertion("block_position", value.block_position);
@@ -148,6 +269,24 @@ impl TryFrom<Envelope> for Transaction {
         let raw = envelope
             .try_optional_object_for_predicate("raw")
             .context("raw")?;
+        let version = envelope
+            .extract_optional_object_for_predicate("version")
+            .context("version")?;
+        let version_group_id = envelope
+            .extract_optional_object_for_predicate("version_group_id")
+            .context("version_group_id")?;
+        let expiry_height = envelope
+            .try_optional_object_for_predicate("expiry_height")
+            .context("expiry_height")?;
+        let lock_time = envelope
+            .extract_optional_object_for_predicate("lock_time")
+            .context("lock_time")?;
+        let sapling_value_balance = envelope
+            .try_optional_object_for_predicate("sapling_value_balance")
+            .context("sapling_value_balance")?;
+        let orchard_value_balance = envelope
+            .try_optional_object_for_predicate("orchard_value_balance")
+            .context("orchard_value_balance")?;
         let target_height = envelope
             .try_optional_object_for_predicate("target_height")
             .context("target_height")?;
@@ -162,6 +301,12 @@ impl TryFrom<Envelope> for Transaction {
         Ok(Self {
             txid,
             raw,
+            version,
+            version_group_id,
+            expiry_height,
+            lock_time,
+            sapling_value_balance,
+            orchard_value_balance,
             target_height,
             mined_height,
             block_position,
@@ -170,25 +315,116 @@ impl TryFrom<Envelope> for Transaction {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for Transaction {
+    fn random() -> Self {
+        Self {
+            txid: TxId::random(),
+            raw: Data::opt_random(),
+            version: u32::opt_random(),
+            version_group_id: u32::opt_random(),
+            expiry_height: BlockHeight::opt_random(),
+            lock_time: u32::opt_random(),
+            sapling_value_balance: Amount::opt_random(),
+            orchard_value_balance: Amount::opt_random(),
+            target_height: BlockHeight::opt_random(),
+            mined_height: BlockHeight::opt_random(),
+            block_position: TxBlockPosition::opt_random(),
+            attachments: Attachments::random(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use bc_envelope::Attachments;
+    use bc_envelope::prelude::*;
 
     use super::Transaction;
-    use crate::{BlockHeight, Data, TxBlockPosition, TxId, test_envelope_roundtrip};
-
-    impl crate::RandomInstance for Transaction {
-        fn random() -> Self {
-            Self {
-                txid: TxId::random(),
-                raw: Data::opt_random(),
-                target_height: BlockHeight::opt_random(),
-                mined_height: BlockHeight::opt_random(),
-                block_position: TxBlockPosition::opt_random(),
-                attachments: Attachments::random(),
-            }
-        }
-    }
+    use crate::{Amount, BlockHeight, Data, TxId, test_envelope_roundtrip};
 
     test_envelope_roundtrip!(Transaction);
+
+    #[test]
+    fn test_version_and_expiry_roundtrip() {
+        let mut tx = Transaction::new(TxId::from_bytes([1u8; 32]));
+        tx.set_version(5);
+        tx.set_version_group_id(0x26a7270a);
+        tx.set_expiry_height(BlockHeight::from_u32(2_500_040));
+
+        let decoded = Transaction::try_from(Envelope::from(tx.clone())).unwrap();
+        assert_eq!(decoded.version(), Some(5));
+        assert_eq!(decoded.version_group_id(), Some(0x26a7270a));
+        assert_eq!(
+            decoded.expiry_height(),
+            Some(&BlockHeight::from_u32(2_500_040))
+        );
+        assert_eq!(decoded, tx);
+    }
+
+    #[test]
+    fn test_lock_time_roundtrip() {
+        let mut tx = Transaction::new(TxId::from_bytes([3u8; 32]));
+        assert_eq!(tx.lock_time(), None);
+        tx.set_lock_time(1_700_000_000);
+
+        let decoded = Transaction::try_from(Envelope::from(tx.clone())).unwrap();
+        assert_eq!(decoded.lock_time(), Some(1_700_000_000));
+        assert_eq!(decoded, tx);
+    }
+
+    #[test]
+    fn test_value_balance_roundtrip() {
+        let mut tx = Transaction::new(TxId::from_bytes([2u8; 32]));
+        assert_eq!(tx.sapling_value_balance(), None);
+        tx.set_sapling_value_balance(Amount::from_i64(150_000).unwrap());
+        tx.set_orchard_value_balance(Amount::from_i64(-2_500_000).unwrap());
+
+        let decoded = Transaction::try_from(Envelope::from(tx.clone())).unwrap();
+        assert_eq!(
+            decoded.sapling_value_balance(),
+            Some(Amount::from_i64(150_000).unwrap())
+        );
+        assert_eq!(
+            decoded.orchard_value_balance(),
+            Some(Amount::from_i64(-2_500_000).unwrap())
+        );
+        assert_eq!(decoded, tx);
+    }
+
+    // The coinbase of the Zcash mainnet genesis block, a version 1 transaction whose only
+    // output pays zero zatoshis.
+    const GENESIS_COINBASE_HEX: &str = "01000000010000000000000000000000000000000000000000000000000000000000000000ffffffff4d04ffff071f0104455a6361736830623963346565663862376363343137656535303031653335303039383462366665613335363833613763616331343161303433633432303634383335643334ffffffff010000000000000000434104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac00000000";
+    const GENESIS_COINBASE_TXID: &str =
+        "c4eaa58879081de3c24a7b117ed2b28300e7ec4c4c1dff1d3f1268b7857a4ddb";
+
+    #[test]
+    fn test_compute_txid_v1() {
+        let txid = TxId::from_hex(GENESIS_COINBASE_TXID).unwrap();
+        let mut tx = Transaction::new(txid);
+        tx.set_raw(Data::from_hex(GENESIS_COINBASE_HEX).unwrap());
+        assert_eq!(tx.compute_txid().unwrap(), txid);
+        assert_eq!(
+            tx.compute_txid().unwrap().to_string(),
+            GENESIS_COINBASE_TXID
+        );
+
+        tx.set_version(1);
+        assert_eq!(tx.compute_txid().unwrap(), txid);
+        tx.set_version(2);
+        assert!(tx.compute_txid().is_err());
+    }
+
+    #[test]
+    fn test_compute_txid_unsupported() {
+        let mut tx = Transaction::new(TxId::from_bytes([0u8; 32]));
+        assert!(tx.compute_txid().is_err());
+
+        // A v4 (Sapling) header: version 4 with the overwintered flag set.
+        tx.set_raw(Data::from_hex("0400008085202f89").unwrap());
+        let error = tx.compute_txid().unwrap_err();
+        assert!(error.to_string().contains("version 4"));
+
+        tx.set_raw(Data::from_hex("0100").unwrap());
+        assert!(tx.compute_txid().is_err());
+    }
 }
@@ -1,7 +1,7 @@
-use crate::DerivationInfo;
+use crate::{DerivationInfo, Network, Op, Script, base58};
 
-use super::TransparentSpendAuthority;
-use anyhow::Context;
+use super::{TransparentKind, TransparentSpendAuthority};
+use anyhow::{Context, Result, bail};
 use bc_envelope::prelude::*;
 
 /// A transparent address on the Zcash network.
@@ -143,6 +143,110 @@ impl Address {
     pub fn set_derivation_info(&mut self, derivation_info: DerivationInfo) {
         self.derivation_info = Some(derivation_info);
     }
+
+    /// Reconstructs the standard `script_pubkey` that pays to this address.
+    ///
+    /// The address string is Base58Check-decoded and its two-byte version prefix determines
+    /// the script form:
+    ///
+    /// - P2PKH: `OP_DUP OP_HASH160 <20-byte hash> OP_EQUALVERIFY OP_CHECKSIG`
+    /// - P2SH: `OP_HASH160 <20-byte hash> OP_EQUAL`
+    ///
+    /// Mainnet, testnet and regtest prefixes are all accepted.
+    ///
+    /// # Errors
+    /// Returns an error if the address is not valid Base58Check, does not carry a 20-byte
+    /// hash, or has an unrecognized version prefix.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::transparent;
+    /// let address = transparent::Address::new("t1Hxw6JqWMnhDK5jRCieg5bFHM2qt7UtQvu");
+    /// let script = address.to_script_pubkey().unwrap();
+    /// assert_eq!(script.len(), 25);
+    /// ```
+    pub fn to_script_pubkey(&self) -> Result<Script> {
+        let (prefix, hash) = self.decode()?;
+        let networks = [Network::Main, Network::Test];
+        let script = if networks
+            .iter()
+            .any(|n| n.transparent_pubkey_prefix() == prefix)
+        {
+            Script::builder()
+                .push_opcode(Op::OP_DUP)
+                .push_opcode(Op::OP_HASH160)
+                .push_slice(&hash)
+                .push_opcode(Op::OP_EQUALVERIFY)
+                .push_opcode(Op::OP_CHECKSIG)
+                .build()
+        } else if networks
+            .iter()
+            .any(|n| n.transparent_script_prefix() == prefix)
+        {
+            Script::builder()
+                .push_opcode(Op::OP_HASH160)
+                .push_slice(&hash)
+                .push_opcode(Op::OP_EQUAL)
+                .build()
+        } else {
+            bail!(
+                "Unrecognized transparent address prefix: {}",
+                hex::encode(prefix)
+            );
+        };
+        Ok(script)
+    }
+
+    /// Determines whether this is a P2PKH or P2SH address on `network`.
+    ///
+    /// # Errors
+    /// Returns an error if the address is not valid Base58Check, does not carry a 20-byte
+    /// hash, or has a version prefix that does not belong to `network`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::{Network, transparent::{self, TransparentKind}};
+    /// let address = transparent::Address::new("t3Jex1rKwuh1bQFRrKpKGWDcDVZ8bbQuNrB");
+    /// assert_eq!(address.kind(Network::Main).unwrap(), TransparentKind::P2sh);
+    /// assert!(address.kind(Network::Test).is_err());
+    /// ```
+    pub fn kind(&self, network: Network) -> Result<TransparentKind> {
+        let prefix = self.version_prefix()?;
+        if prefix == network.transparent_pubkey_prefix() {
+            Ok(TransparentKind::P2pkh)
+        } else if prefix == network.transparent_script_prefix() {
+            Ok(TransparentKind::P2sh)
+        } else {
+            bail!(
+                "Transparent address prefix {} does not belong to the {} network",
+                hex::encode(prefix),
+                network
+            );
+        }
+    }
+
+    /// Returns the two-byte Base58Check version prefix of this address, which identifies both
+    /// its network and whether it is P2PKH or P2SH.
+    ///
+    /// # Errors
+    /// Returns an error if the address is not valid Base58Check or does not carry a 20-byte
+    /// hash.
+    pub(crate) fn version_prefix(&self) -> Result<[u8; 2]> {
+        Ok(self.decode()?.0)
+    }
+
+    /// Decodes the address string into its version prefix and 20-byte hash.
+    fn decode(&self) -> Result<([u8; 2], [u8; 20])> {
+        let payload = base58::decode_check(&self.address).context("TransparentAddress")?;
+        if payload.len() != 22 {
+            bail!(
+                "Transparent address payload has {} bytes, expected 22",
+                payload.len()
+            );
+        }
+        let (prefix, hash) = payload.split_at(2);
+        Ok((prefix.try_into()?, hash.try_into()?))
+    }
 }
 
 impl From<Address> for Envelope {
@@ -176,7 +280,7 @@ impl TryFrom<Envelope> for Address {
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "test-dependencies"))]
 impl crate::RandomInstance for Address {
     fn random() -> Self {
         Self {
@@ -189,8 +293,90 @@ impl crate::RandomInstance for Address {
 
 #[cfg(test)]
 mod tests {
-    use super::Address;
-    use crate::test_envelope_roundtrip;
+    use super::{Address, TransparentKind};
+    use crate::{Data, Network, Op, test_envelope_roundtrip};
 
     test_envelope_roundtrip!(Address);
+
+    const HASH: [u8; 20] = [
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+    ];
+
+    #[test]
+    fn test_p2pkh_script_pubkey() {
+        for encoded in [
+            "t1Hxw6JqWMnhDK5jRCieg5bFHM2qt7UtQvu",
+            "tm9ogR9KukTCiTKvrsSxQwFv2x1vhZTydav",
+        ] {
+            let script = Address::new(encoded).to_script_pubkey().unwrap();
+            let ops: Vec<Op> = script.iter_ops().collect::<anyhow::Result<_>>().unwrap();
+            assert_eq!(
+                ops,
+                vec![
+                    Op::Opcode(Op::OP_DUP),
+                    Op::Opcode(Op::OP_HASH160),
+                    Op::Push(Data::from_slice(&HASH)),
+                    Op::Opcode(Op::OP_EQUALVERIFY),
+                    Op::Opcode(Op::OP_CHECKSIG),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_p2sh_script_pubkey() {
+        let script = Address::new("t3Jex1rKwuh1bQFRrKpKGWDcDVZ8bbQuNrB")
+            .to_script_pubkey()
+            .unwrap();
+        let ops: Vec<Op> = script.iter_ops().collect::<anyhow::Result<_>>().unwrap();
+        assert_eq!(
+            ops,
+            vec![
+                Op::Opcode(Op::OP_HASH160),
+                Op::Push(Data::from_slice(&HASH)),
+                Op::Opcode(Op::OP_EQUAL),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_kind() {
+        let p2pkh = Address::new("t1Hxw6JqWMnhDK5jRCieg5bFHM2qt7UtQvu");
+        let p2sh = Address::new("t3Jex1rKwuh1bQFRrKpKGWDcDVZ8bbQuNrB");
+        assert_eq!(p2pkh.kind(Network::Main).unwrap(), TransparentKind::P2pkh);
+        assert_eq!(p2sh.kind(Network::Main).unwrap(), TransparentKind::P2sh);
+        assert!(p2pkh.kind(Network::Test).is_err());
+
+        let test_p2sh = Address::new("t26e94XS5n9cxwx1bFZKK3qnrc3MmURMBS5");
+        assert_eq!(
+            test_p2sh.kind(Network::Test).unwrap(),
+            TransparentKind::P2sh
+        );
+        assert_eq!(
+            test_p2sh.kind(Network::Regtest).unwrap(),
+            TransparentKind::P2sh
+        );
+        assert!(test_p2sh.kind(Network::Main).is_err());
+        assert!(
+            Address::new("t1exampleaddress0")
+                .kind(Network::Main)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_invalid_script_pubkey() {
+        // Corrupted checksum
+        assert!(
+            Address::new("t1Hxw6JqWMnhDK5jRCieg5bFHM2qt7UtQvv")
+                .to_script_pubkey()
+                .is_err()
+        );
+        // Not Base58
+        assert!(
+            Address::new("t1exampleaddress0")
+                .to_script_pubkey()
+                .is_err()
+        );
+    }
 }
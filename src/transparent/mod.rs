@@ -3,5 +3,6 @@
 use crate::mod_use;
 
 mod_use!(address);
+mod_use!(transparent_kind);
 mod_use!(transparent_spending_key);
 mod_use!(transparent_spend_authority);
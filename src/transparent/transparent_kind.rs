@@ -0,0 +1,12 @@
+/// The form of a transparent address, as indicated by its Base58Check version prefix.
+///
+/// Returned by [`Address::kind`](super::Address::kind).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransparentKind {
+    /// Pay to public key hash: the funds are locked to the hash of a single public key. Mainnet
+    /// P2PKH addresses begin with `t1`.
+    P2pkh,
+    /// Pay to script hash: the funds are locked to the hash of a redeem script, such as a
+    /// multisig script. Mainnet P2SH addresses begin with `t3`.
+    P2sh,
+}
@@ -73,7 +73,7 @@ impl TryFrom<Envelope> for TransparentSpendAuthority {
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "test-dependencies"))]
 impl crate::RandomInstance for TransparentSpendAuthority {
     fn random() -> Self {
         let mut rng = rand::thread_rng();
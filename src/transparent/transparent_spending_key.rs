@@ -10,8 +10,18 @@ use crate::{blob, blob_envelope};
 blob!(
     TransparentSpendingKey,
     32,
-    "A Zcash transparent private key"
+    r#"A Zcash transparent private key.
+
+The key bytes are overwritten with zeros when the key is dropped. Because a type with a
+`Drop` implementation cannot be `Copy`, this type is `Clone` but no longer `Copy`; code that
+relied on implicit copies must call `clone()` explicitly."#
 );
-impl Copy for TransparentSpendingKey {}
 
 blob_envelope!(TransparentSpendingKey);
+
+/// The key bytes are overwritten with zeros when the key is dropped.
+impl Drop for TransparentSpendingKey {
+    fn drop(&mut self) {
+        bc_crypto::memzero(&mut self.0);
+    }
+}
@@ -65,20 +65,21 @@ impl TryFrom<Envelope> for TxBlockPosition {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for TxBlockPosition {
+    fn random() -> Self {
+        Self {
+            block_hash: BlockHash::random(),
+            index: u32::random(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod envelope_tests {
-    use crate::{BlockHash, test_envelope_roundtrip};
+    use crate::test_envelope_roundtrip;
 
     use super::TxBlockPosition;
 
-    impl crate::RandomInstance for TxBlockPosition {
-        fn random() -> Self {
-            Self {
-                block_hash: BlockHash::random(),
-                index: u32::random(),
-            }
-        }
-    }
-
     test_envelope_roundtrip!(TxBlockPosition);
 }
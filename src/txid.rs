@@ -110,6 +110,36 @@ impl TxId {
         })?))
     }
 
+    /// Parses one `TxId` per line, as with [`from_hex`](Self::from_hex).
+    ///
+    /// Surrounding whitespace is trimmed and blank lines are skipped. Every other line
+    /// produces its own result, so one malformed line does not prevent the rest from being
+    /// read; errors name the 1-based line number they came from.
+    ///
+    /// # Examples
+    /// ```
+    /// # use zewif::TxId;
+    /// let text = "
+    ///     0000000000000000000000000000000000000000000000000000000000000001
+    ///     not a txid
+    /// ";
+    /// let results = TxId::parse_many(text.lines());
+    /// assert_eq!(results.len(), 2);
+    /// assert!(results[0].is_ok());
+    /// assert!(results[1].as_ref().unwrap_err().to_string().contains("line 3"));
+    /// ```
+    pub fn parse_many<'a>(lines: impl IntoIterator<Item = &'a str>) -> Vec<Result<TxId>> {
+        lines
+            .into_iter()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(number, line)| {
+                TxId::from_hex(line).with_context(|| format!("line {}: {:?}", number, line))
+            })
+            .collect()
+    }
+
     /// Reads a `TxId` from any source implementing the `Read` trait.
     ///
     /// This method is useful when reading transaction IDs directly from files
@@ -216,19 +246,67 @@ impl TryFrom<Envelope> for TxId {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for TxId {
+    fn random() -> Self {
+        let mut rng = bc_rand::thread_rng();
+        Self(bc_rand::rng_random_array(&mut rng))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{test_cbor_roundtrip, test_envelope_roundtrip};
 
     use super::TxId;
 
-    impl crate::RandomInstance for TxId {
-        fn random() -> Self {
-            let mut rng = bc_rand::thread_rng();
-            Self(bc_rand::rng_random_array(&mut rng))
-        }
-    }
-
     test_cbor_roundtrip!(TxId);
     test_envelope_roundtrip!(TxId);
+
+    #[test]
+    fn test_display_explorer_format() {
+        // The coinbase transaction of the Zcash mainnet genesis block, as shown by explorers.
+        let explorer = "c4eaa58879081de3c24a7b117ed2b28300e7ec4c4c1dff1d3f1268b7857a4ddb";
+        let mut bytes: [u8; 32] = hex::decode(explorer).unwrap().try_into().unwrap();
+        bytes.reverse();
+        let txid = TxId::from_bytes(bytes);
+        assert_eq!(txid.as_ref()[0], 0xdb);
+        assert_eq!(txid.to_string(), explorer);
+        assert_eq!(TxId::from_hex(explorer).unwrap(), txid);
+    }
+
+    #[test]
+    fn test_parse_many() {
+        let first = TxId::from_bytes([1u8; 32]);
+        let second = TxId::from_bytes([2u8; 32]);
+        let text = format!("{}\n\n  {}  \nxyz\n{}00\n", first, second, first);
+
+        let results = TxId::parse_many(text.lines());
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap(), &first);
+        assert_eq!(results[1].as_ref().unwrap(), &second);
+        let error = results[2].as_ref().unwrap_err().to_string();
+        assert!(error.contains("line 4"), "{}", error);
+        let error = results[3].as_ref().unwrap_err().to_string();
+        assert!(error.contains("line 5"), "{}", error);
+
+        assert!(TxId::parse_many(["", "   "]).is_empty());
+    }
+
+    #[test]
+    fn test_ordering_by_raw_bytes() {
+        let mut low = [0u8; 32];
+        low[0] = 1;
+        low[31] = 0xff;
+        let mut high = [0u8; 32];
+        high[0] = 2;
+        let (low, high) = (TxId::from_bytes(low), TxId::from_bytes(high));
+
+        // Ordering follows the raw bytes, not the byte-reversed display string.
+        assert!(low < high);
+        assert!(low.to_string() > high.to_string());
+
+        let set: std::collections::BTreeSet<TxId> = [high, low, high].into_iter().collect();
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![low, high]);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::Blob;
+use crate::{Blob, Network};
 use anyhow::Context;
 use bc_envelope::prelude::*;
 
@@ -158,21 +158,37 @@ impl TryFrom<Envelope> for UnifiedAddress {
     }
 }
 
+/// Returns `true` if `address` carries the Bech32m human-readable part of a Unified Address on
+/// any network.
+///
+/// Only the prefix before the final `1` separator is examined; the encoding itself is not
+/// validated.
+pub(crate) fn has_unified_hrp(address: &str) -> bool {
+    let Some(separator) = address.rfind('1') else {
+        return false;
+    };
+    let hrp = &address[..separator];
+    [Network::Main, Network::Test, Network::Regtest]
+        .iter()
+        .any(|network| hrp.eq_ignore_ascii_case(network.unified_hrp()))
+}
+
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for UnifiedAddress {
+    fn random() -> Self {
+        Self {
+            address: String::random(),
+            diversifier_index: Blob::opt_random(),
+            hd_derivation_path: String::opt_random(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Blob, test_envelope_roundtrip};
+    use crate::test_envelope_roundtrip;
 
     use super::UnifiedAddress;
 
-    impl crate::RandomInstance for UnifiedAddress {
-        fn random() -> Self {
-            Self {
-                address: String::random(),
-                diversifier_index: Blob::opt_random(),
-                hd_derivation_path: String::opt_random(),
-            }
-        }
-    }
-
     test_envelope_roundtrip!(UnifiedAddress);
 }
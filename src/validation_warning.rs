@@ -0,0 +1,61 @@
+use crate::TxId;
+
+/// A structural inconsistency found by [`Zewif::validate`](crate::Zewif::validate).
+///
+/// Warnings identify the offending wallet and account by their indexes so that callers can
+/// report every problem found in a container rather than stopping at the first one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationWarning {
+    /// An account lists a relevant transaction that is not in the global transaction map.
+    DanglingTransactionReference {
+        wallet_index: usize,
+        account_index: usize,
+        txid: TxId,
+    },
+    /// An account's address indexes are not contiguous starting at 0.
+    NonContiguousAddressIndex {
+        wallet_index: usize,
+        account_index: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// More than one account in a wallet has the same index.
+    DuplicateAccountIndex {
+        wallet_index: usize,
+        account_index: usize,
+    },
+}
+
+impl std::fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationWarning::DanglingTransactionReference {
+                wallet_index,
+                account_index,
+                txid,
+            } => write!(
+                f,
+                "wallet {} account {} references missing transaction {}",
+                wallet_index, account_index, txid
+            ),
+            ValidationWarning::NonContiguousAddressIndex {
+                wallet_index,
+                account_index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "wallet {} account {} has address index {} where {} was expected",
+                wallet_index, account_index, found, expected
+            ),
+            ValidationWarning::DuplicateAccountIndex {
+                wallet_index,
+                account_index,
+            } => write!(
+                f,
+                "wallet {} has more than one account with index {}",
+                wallet_index, account_index
+            ),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{Account, Address, BlockHeight, Network, Zewif, ZewifWallet};
+
+/// A fluent builder for [`Zewif`] containers, mainly for tests and examples.
+///
+/// The builder keeps a current wallet and a current account. [`wallet`](Self::wallet) starts
+/// a new wallet, [`account`](Self::account) starts a new account in the current wallet, and
+/// [`address`](Self::address) adds an address to the current account. Indexes are assigned
+/// in the order items are added.
+///
+/// # Examples
+/// ```
+/// # use zewif::{Address, BlockHeight, Network, ProtocolAddress, Zewif, transparent};
+/// let address = Address::new(ProtocolAddress::Transparent(transparent::Address::new("t1a")));
+/// let zewif = Zewif::builder(BlockHeight::from_u32(2_000_000))
+///     .wallet(Network::Main)
+///     .account("Spending")
+///     .address(address)
+///     .finish();
+/// assert_eq!(zewif.wallets()[0].accounts()[0].addresses_len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ZewifBuilder {
+    zewif: Zewif,
+    wallet: Option<ZewifWallet>,
+    account: Option<Account>,
+}
+
+impl ZewifBuilder {
+    pub fn new(export_height: BlockHeight) -> Self {
+        Self {
+            zewif: Zewif::new(export_height),
+            wallet: None,
+            account: None,
+        }
+    }
+
+    /// Starts a new wallet on `network`, which becomes the current wallet.
+    pub fn wallet(mut self, network: Network) -> Self {
+        self.finish_wallet();
+        self.wallet = Some(ZewifWallet::new(network));
+        self
+    }
+
+    /// Starts a new account named `name` in the current wallet, which becomes the current
+    /// account.
+    ///
+    /// # Panics
+    /// Panics if no wallet has been started.
+    pub fn account(mut self, name: impl Into<String>) -> Self {
+        self.finish_account();
+        assert!(
+            self.wallet.is_some(),
+            "ZewifBuilder::account called before wallet"
+        );
+        let mut account = Account::new();
+        account.set_name(name);
+        self.account = Some(account);
+        self
+    }
+
+    /// Adds `address` to the current account.
+    ///
+    /// # Panics
+    /// Panics if no account has been started in the current wallet.
+    pub fn address(mut self, address: Address) -> Self {
+        self.account
+            .as_mut()
+            .expect("ZewifBuilder::address called before account")
+            .add_address(address);
+        self
+    }
+
+    /// Consumes the builder and returns the assembled container.
+    pub fn finish(mut self) -> Zewif {
+        self.finish_wallet();
+        self.zewif
+    }
+
+    fn finish_account(&mut self) {
+        if let Some(account) = self.account.take() {
+            // An account is only ever started inside a wallet.
+            self.wallet.as_mut().unwrap().add_account(account);
+        }
+    }
+
+    fn finish_wallet(&mut self) {
+        self.finish_account();
+        if let Some(wallet) = self.wallet.take() {
+            self.zewif.add_wallet(wallet);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, BlockHeight, Indexed, Network, ProtocolAddress, Zewif, transparent};
+
+    fn address(s: &str) -> Address {
+        Address::new(ProtocolAddress::Transparent(transparent::Address::new(s)))
+    }
+
+    #[test]
+    fn test_two_wallets() {
+        let zewif = Zewif::builder(BlockHeight::from_u32(2_000_000))
+            .wallet(Network::Main)
+            .account("Spending")
+            .address(address("t1a"))
+            .address(address("t1b"))
+            .account("Savings")
+            .wallet(Network::Test)
+            .account("Testing")
+            .address(address("tm1"))
+            .finish();
+
+        assert_eq!(zewif.export_height(), BlockHeight::from_u32(2_000_000));
+        let wallets = zewif.wallets();
+        assert_eq!(wallets.len(), 2);
+        assert_eq!(wallets[1].index(), 1);
+        assert_eq!(wallets[0].network(), Network::Main);
+        assert_eq!(wallets[1].network(), Network::Test);
+
+        let accounts = wallets[0].accounts();
+        let names: Vec<&str> = accounts.iter().map(|a| a.name()).collect();
+        assert_eq!(names, vec!["Spending", "Savings"]);
+        assert_eq!(accounts[1].index(), 1);
+        let addresses: Vec<String> = accounts[0]
+            .addresses()
+            .iter()
+            .map(|a| a.as_string())
+            .collect();
+        assert_eq!(addresses, vec!["t1a", "t1b"]);
+        assert_eq!(accounts[0].addresses()[1].index(), 1);
+        assert_eq!(accounts[1].addresses_len(), 0);
+
+        let accounts = wallets[1].accounts();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].addresses()[0].as_string(), "tm1");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_account_without_wallet() {
+        let _ = Zewif::builder(BlockHeight::from_u32(1)).account("Orphan");
+    }
+}
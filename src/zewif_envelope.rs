@@ -117,6 +117,21 @@ impl ZewifEnvelope {
         }
         Ok(())
     }
+
+    /// Re-encrypts the envelope under `new_key`, leaving any compression layer in place.
+    ///
+    /// Fails without modifying the envelope if it is not encrypted or if `old_key` does not
+    /// decrypt it.
+    pub fn rekey(&mut self, old_key: &SymmetricKey, new_key: &SymmetricKey) -> Result<()> {
+        if !self.can_decrypt() {
+            bail!("Cannot rekey a Zewif that has not been encrypted");
+        }
+        let mut rekeyed = self.clone();
+        rekeyed.decrypt(old_key).context("old key")?;
+        rekeyed.encrypt(new_key)?;
+        *self = rekeyed;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -217,4 +232,28 @@ mod tests {
         // Check that the reconstructed Zewif instance matches the original
         assert_eq!(zewif, zewif2);
     }
+
+    #[test]
+    fn test_rekey() {
+        let ze = ZewifEnvelope::new(Envelope::from(Zewif::random())).unwrap();
+        let old_key = ZewifEnvelope::derive_encryption_key("old password");
+        let new_key = ZewifEnvelope::derive_encryption_key("new password");
+
+        let mut rekeyed = ze.clone();
+        assert!(rekeyed.rekey(&old_key, &new_key).is_err());
+        rekeyed.compress().unwrap();
+        rekeyed.encrypt(&old_key).unwrap();
+        let before = rekeyed.clone();
+        assert!(rekeyed.rekey(&new_key, &new_key).is_err());
+        assert_eq!(rekeyed.envelope(), before.envelope());
+
+        rekeyed.rekey(&old_key, &new_key).unwrap();
+        assert!(rekeyed.is_encrypted());
+        assert!(rekeyed.clone().decrypt(&old_key).is_err());
+
+        rekeyed.decrypt(&new_key).unwrap();
+        assert!(rekeyed.is_compressed());
+        rekeyed.uncompress().unwrap();
+        assert_eq!(rekeyed.digest(), ze.digest());
+    }
 }
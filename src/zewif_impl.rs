@@ -1,12 +1,24 @@
-use anyhow::Context;
+use anyhow::{Context, Result, bail};
 use bc_components::ARID;
 use bc_envelope::prelude::*;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
-use crate::{BlockHeight, Indexed, envelope_indexed_objects_for_predicate};
+use crate::{
+    Account, AttachmentStats, AttachmentsExt, BlockHeight, DiffEntry, DiffKind, Indexed,
+    SizeReport, ValidationWarning, ZewifBuilder, ZewifEnvelope, ZewifVisitor, diff_keyed,
+    envelope_indexed_objects_for_predicate, set_indexes,
+};
 
 use super::{Transaction, TxId, ZewifWallet};
 
+/// The version of the ZeWIF envelope format written by this library.
+///
+/// Readers reject containers whose `format_version` is newer than this value.
+///
+/// Version 2 writes account assertion predicates as known values rather than strings;
+/// version 1 containers are still accepted.
+pub const ZEWIF_FORMAT_VERSION: u32 = 2;
+
 /// The top-level container for the Zcash Wallet Interchange Format (ZeWIF).
 ///
 /// `Zewif` is the root structure of the ZeWIF hierarchy, serving as a container
@@ -60,6 +72,7 @@ pub struct Zewif {
     wallets: Vec<ZewifWallet>,
     transactions: HashMap<TxId, Transaction>,
     export_height: BlockHeight,
+    format_version: u32,
     attachments: Attachments,
 }
 
@@ -72,10 +85,17 @@ impl Zewif {
             wallets: Vec::new(),
             transactions: HashMap::new(),
             export_height,
+            format_version: ZEWIF_FORMAT_VERSION,
             attachments: Attachments::new(),
         }
     }
 
+    /// Returns a [`ZewifBuilder`] for assembling a container from nested wallets, accounts
+    /// and addresses.
+    pub fn builder(export_height: BlockHeight) -> ZewifBuilder {
+        ZewifBuilder::new(export_height)
+    }
+
     pub fn id(&self) -> ARID {
         self.id
     }
@@ -93,6 +113,31 @@ impl Zewif {
         self.wallets.push(wallet);
     }
 
+    /// Removes duplicate addresses within each account of each wallet, returning the number
+    /// removed.
+    ///
+    /// Within an account the first occurrence of an address is kept, taking the name or
+    /// purpose of a removed duplicate where its own is empty; see
+    /// [`Account::dedup_addresses`](crate::Account::dedup_addresses). Duplicates across
+    /// different accounts are intentionally preserved, since each account's address list
+    /// records which addresses that account can receive on.
+    pub fn dedup_addresses_within_accounts(&mut self) -> usize {
+        self.wallets
+            .iter_mut()
+            .map(ZewifWallet::dedup_addresses_within_accounts)
+            .sum()
+    }
+
+    /// Removes the wallets for which `f` returns `false`, keeping the rest in order.
+    ///
+    /// The remaining wallets are re-indexed to be contiguous from 0, so a wallet's index may
+    /// change. Transactions referenced only by removed wallets stay in the global transaction
+    /// map; see [`prune_orphan_transactions`](Self::prune_orphan_transactions).
+    pub fn retain_wallets(&mut self, f: impl FnMut(&ZewifWallet) -> bool) {
+        self.wallets.retain(f);
+        self.wallets = set_indexes(std::mem::take(&mut self.wallets));
+    }
+
     pub fn transactions(&self) -> &HashMap<TxId, Transaction> {
         &self.transactions
     }
@@ -105,6 +150,33 @@ impl Zewif {
         self.transactions.get(&txid)
     }
 
+    /// Looks up a transaction in the global transaction map by its ID.
+    pub fn find_transaction(&self, txid: &TxId) -> Option<&Transaction> {
+        self.transactions.get(txid)
+    }
+
+    /// Looks up a transaction in the global transaction map by its ID, for modification.
+    pub fn find_transaction_mut(&mut self, txid: &TxId) -> Option<&mut Transaction> {
+        self.transactions.get_mut(txid)
+    }
+
+    /// Returns every account that lists `txid` among its relevant transactions, together with
+    /// the wallet containing it, in wallet and account order.
+    ///
+    /// This is a linear scan of all accounts.
+    pub fn accounts_for_transaction(&self, txid: &TxId) -> Vec<(&ZewifWallet, &Account)> {
+        self.wallets
+            .iter()
+            .flat_map(|wallet| {
+                wallet
+                    .accounts()
+                    .iter()
+                    .map(move |account| (wallet, account))
+            })
+            .filter(|(_, account)| account.relevant_transactions().contains(txid))
+            .collect()
+    }
+
     pub fn set_transactions(&mut self, transactions: HashMap<TxId, Transaction>) {
         self.transactions = transactions;
     }
@@ -112,6 +184,245 @@ impl Zewif {
     pub fn export_height(&self) -> BlockHeight {
         self.export_height
     }
+
+    pub fn set_export_height(&mut self, export_height: BlockHeight) {
+        self.export_height = export_height;
+    }
+
+    /// The version of the envelope format this container will be written as.
+    ///
+    /// This is always [`ZEWIF_FORMAT_VERSION`]: decoding accepts older versions but upgrades
+    /// the container, since it is written back with the current encoding.
+    pub fn format_version(&self) -> u32 {
+        self.format_version
+    }
+
+    /// Checks the container for internal inconsistencies.
+    ///
+    /// Every problem found is reported as a [`ValidationWarning`] rather than aborting at the
+    /// first one, so an empty vector means the container is structurally sound. The checks are:
+    ///
+    /// - every transaction in an account's `relevant_transactions` exists in the global
+    ///   transaction map,
+    /// - the address indexes within each account are contiguous starting at 0, and
+    /// - no two accounts in the same wallet share an index.
+    pub fn validate(&self) -> Result<Vec<ValidationWarning>> {
+        let mut warnings = Vec::new();
+        for wallet in &self.wallets {
+            let wallet_index = wallet.index();
+            let mut seen_account_indexes = HashSet::new();
+            for account in wallet.accounts() {
+                let account_index = account.index();
+                if !seen_account_indexes.insert(account_index) {
+                    warnings.push(ValidationWarning::DuplicateAccountIndex {
+                        wallet_index,
+                        account_index,
+                    });
+                }
+
+                let mut txids: Vec<_> = account.relevant_transactions().iter().collect();
+                txids.sort();
+                for txid in txids {
+                    if !self.transactions.contains_key(txid) {
+                        warnings.push(ValidationWarning::DanglingTransactionReference {
+                            wallet_index,
+                            account_index,
+                            txid: *txid,
+                        });
+                    }
+                }
+
+                for (expected, address) in account.addresses().iter().enumerate() {
+                    if address.index() != expected {
+                        warnings.push(ValidationWarning::NonContiguousAddressIndex {
+                            wallet_index,
+                            account_index,
+                            expected,
+                            found: address.index(),
+                        });
+                    }
+                }
+            }
+        }
+        Ok(warnings)
+    }
+
+    /// Removes global transactions that no account lists among its relevant transactions,
+    /// returning the number of transactions removed.
+    ///
+    /// This is destructive: transaction data that is not referenced by any account is
+    /// discarded. It is intended to be run after [`validate`](Self::validate) has confirmed
+    /// that account references are otherwise consistent.
+    pub fn prune_orphan_transactions(&mut self) -> usize {
+        let referenced: HashSet<TxId> = self
+            .wallets
+            .iter()
+            .flat_map(|wallet| wallet.accounts())
+            .flat_map(|account| account.relevant_transactions())
+            .copied()
+            .collect();
+        let before = self.transactions.len();
+        self.transactions
+            .retain(|txid, _| referenced.contains(txid));
+        before - self.transactions.len()
+    }
+
+    /// Lists the structural differences between this container and `other`.
+    ///
+    /// Entries are reported as seen from `self`: an item present only in `other` is
+    /// [`Added`](DiffKind::Added), and one present only in `self` is
+    /// [`Removed`](DiffKind::Removed). Wallets and accounts are matched by index, addresses by
+    /// their string form and transactions by ID, so the order in which addresses and
+    /// transactions were stored does not produce differences. The container's `id` is not
+    /// compared, since a re-imported container is expected to have a new one.
+    ///
+    /// An empty result means the two containers hold the same wallet data.
+    pub fn diff(&self, other: &Zewif) -> Vec<DiffEntry> {
+        let mut diffs = Vec::new();
+        if self.export_height != other.export_height {
+            diffs.push(DiffEntry::new("export_height", DiffKind::Changed));
+        }
+        if self.attachments != other.attachments {
+            diffs.push(DiffEntry::new("attachments", DiffKind::Changed));
+        }
+
+        fn wallets_by_index(zewif: &Zewif) -> BTreeMap<usize, &ZewifWallet> {
+            zewif.wallets.iter().map(|w| (w.index(), w)).collect()
+        }
+        diff_keyed(
+            "",
+            "wallets",
+            wallets_by_index(self),
+            wallets_by_index(other),
+            &mut diffs,
+            |item, ours, theirs, diffs| ours.diff_into(theirs, item, diffs),
+        );
+
+        fn transactions_by_id(zewif: &Zewif) -> BTreeMap<TxId, &Transaction> {
+            zewif
+                .transactions
+                .iter()
+                .map(|(txid, tx)| (*txid, tx))
+                .collect()
+        }
+        diff_keyed(
+            "",
+            "transactions",
+            transactions_by_id(self),
+            transactions_by_id(other),
+            &mut diffs,
+            |item, ours, theirs, diffs| {
+                if ours != theirs {
+                    diffs.push(DiffEntry::new(item, DiffKind::Changed));
+                }
+            },
+        );
+
+        diffs
+    }
+
+    /// Reports the serialized size of each wallet and of the global transaction map, to show
+    /// which parts of an export account for its size.
+    pub fn size_report(&self) -> SizeReport {
+        let size = |envelope: Envelope| envelope.to_cbor_data().len();
+        let wallet_sizes = self
+            .wallets
+            .iter()
+            .map(|wallet| size(wallet.clone().into()))
+            .collect();
+        let transactions_size = self
+            .transactions
+            .values()
+            .map(|transaction| size(transaction.clone().into()))
+            .sum();
+        SizeReport::new(wallet_sizes, transactions_size)
+    }
+
+    /// Walks the container, calling `visitor` for every wallet, account, address and
+    /// transaction.
+    ///
+    /// Wallets, accounts and addresses are visited depth-first in index order. Transactions
+    /// are visited after all wallets, in the unspecified order of the global transaction map.
+    pub fn visit(&self, visitor: &mut impl ZewifVisitor) {
+        for wallet in &self.wallets {
+            visitor.visit_wallet(wallet);
+            for account in wallet.accounts() {
+                visitor.visit_account(account);
+                for address in account.addresses() {
+                    visitor.visit_address(address);
+                }
+            }
+        }
+        for transaction in self.transactions.values() {
+            visitor.visit_transaction(transaction);
+        }
+    }
+
+    /// Counts the attachments carried by the container and all of its wallets, accounts,
+    /// addresses and transactions, and how many distinct digests they have.
+    ///
+    /// Attachments are not deduplicated across objects: each object owns its attachments,
+    /// and removing a repeated one from any object would change that object's contents.
+    pub fn attachment_stats(&self) -> AttachmentStats {
+        let mut all: Vec<&Attachments> = vec![&self.attachments];
+        for wallet in &self.wallets {
+            all.push(wallet.attachments());
+            for account in wallet.accounts() {
+                all.push(account.attachments());
+                all.extend(account.addresses().iter().map(|a| a.attachments()));
+            }
+        }
+        all.extend(self.transactions.values().map(|t| t.attachments()));
+
+        let mut total = 0;
+        let mut digests = HashSet::new();
+        for attachments in all {
+            for (digest, _) in attachments.iter() {
+                total += 1;
+                digests.insert(digest);
+            }
+        }
+        AttachmentStats::new(total, digests.len())
+    }
+
+    /// Deduplicates attachments, which requires no work and leaves the container unchanged.
+    ///
+    /// Each object's [`Attachments`] are keyed by digest, so no object can hold the same
+    /// attachment twice. An attachment repeated on different objects is deliberately kept on
+    /// each of them, because every object owns its attachments and removing one would change
+    /// that object's contents. [`attachment_stats`](Self::attachment_stats) reports how many
+    /// such repeats a container has.
+    pub fn dedup_attachments(&mut self) {}
+
+    /// Produces a password-encrypted export of this container.
+    ///
+    /// The container is compressed before it is encrypted, because encrypted data is not
+    /// compressible. The key is derived from `password` with
+    /// [`ZewifEnvelope::derive_encryption_key`].
+    pub fn to_encrypted_envelope(&self, password: &str) -> Result<Envelope> {
+        let mut ze = ZewifEnvelope::new(Envelope::from(self.clone()))?;
+        ze.compress()?;
+        ze.encrypt(&ZewifEnvelope::derive_encryption_key(password))?;
+        Ok(ze.envelope().clone())
+    }
+
+    /// Reads a container produced by [`to_encrypted_envelope`](Self::to_encrypted_envelope).
+    ///
+    /// # Errors
+    /// Returns an error if the envelope is not an encrypted ZeWIF envelope, if `password` is
+    /// wrong, or if the decrypted contents are not a valid container.
+    pub fn from_encrypted_envelope(envelope: Envelope, password: &str) -> Result<Self> {
+        let mut ze = ZewifEnvelope::new(envelope)?;
+        if !ze.is_encrypted() {
+            bail!("Zewif envelope is not encrypted");
+        }
+        ze.decrypt(&ZewifEnvelope::derive_encryption_key(password))
+            .context("decrypt")?;
+        if ze.is_compressed() {
+            ze.uncompress()?;
+        }
+        Self::try_from(ze.envelope().clone())
+    }
 }
 
 #[rustfmt::skip]
@@ -122,6 +433,7 @@ impl From<Zewif> for Envelope {
         e = value.wallets.iter().fold(e, |e, wallet| e.add_assertion("wallet", wallet.clone()));
         e = value.transactions.iter().fold(e, |e, (_, transaction)| e.add_assertion("transaction", transaction.clone()));
         e = e.add_assertion("export_height", value.export_height);
+        e = e.add_assertion("format_version", value.format_version);
         value.attachments.add_to_envelope(e)
     }
 }
@@ -141,6 +453,15 @@ impl TryFrom<Envelope> for Zewif {
             .into_iter().map(|tx| (tx.txid(), tx)).collect();
 
         let export_height = envelope.extract_object_for_predicate("export_height").context("export_height")?;
+
+        // Containers written before the version assertion was introduced are version 1.
+        let format_version: u32 = envelope.extract_optional_object_for_predicate("format_version").context("format_version")?.unwrap_or(1);
+        if format_version > ZEWIF_FORMAT_VERSION {
+            bail!("Unsupported ZeWIF format version {} (this library supports up to {})", format_version, ZEWIF_FORMAT_VERSION);
+        }
+        // Older containers are upgraded on read, because they are re-encoded in the current format.
+        let format_version = ZEWIF_FORMAT_VERSION;
+
         let attachments = Attachments::try_from_envelope(&envelope).context("attachments")?;
 
         Ok(Self {
@@ -148,36 +469,521 @@ impl TryFrom<Envelope> for Zewif {
             wallets,
             transactions,
             export_height,
+            format_version,
             attachments,
         })
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for Zewif {
+    fn random() -> Self {
+        use crate::SetIndexes;
+
+        Self {
+            id: ARID::new(),
+            wallets: Vec::random().set_indexes(),
+            transactions: Vec::<Transaction>::random()
+                .iter()
+                .map(|tx| (tx.txid(), tx.clone()))
+                .collect(),
+            export_height: BlockHeight::random(),
+            format_version: ZEWIF_FORMAT_VERSION,
+            attachments: Attachments::random(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bc_components::ARID;
-    use bc_envelope::Attachments;
+    use bc_envelope::prelude::*;
 
-    use crate::{BlockHeight, Transaction, test_envelope_roundtrip};
+    use crate::{
+        Account, Address, BlockHeight, DiffEntry, DiffKind, Indexed, Network, ProtocolAddress,
+        RandomInstance, Transaction, TxId, ValidationWarning, ZewifEnvelope, ZewifVisitor,
+        ZewifWallet, test_envelope_roundtrip, transparent,
+    };
 
-    use super::Zewif;
+    use super::{ZEWIF_FORMAT_VERSION, Zewif};
 
-    impl crate::RandomInstance for Zewif {
-        fn random() -> Self {
-            use crate::SetIndexes;
+    test_envelope_roundtrip!(Zewif);
 
-            Self {
-                id: ARID::new(),
-                wallets: Vec::random().set_indexes(),
-                transactions: Vec::<Transaction>::random()
-                    .iter()
-                    .map(|tx| (tx.txid(), tx.clone()))
-                    .collect(),
-                export_height: BlockHeight::random(),
-                attachments: Attachments::random(),
+    #[test]
+    fn test_export_height_roundtrip() {
+        let mut zewif = Zewif::new(BlockHeight::from_u32(2_000_000));
+        assert_eq!(zewif.export_height(), BlockHeight::from_u32(2_000_000));
+        let decoded = Zewif::try_from(Envelope::from(zewif.clone())).unwrap();
+        assert_eq!(decoded.export_height(), BlockHeight::from_u32(2_000_000));
+
+        zewif.set_export_height(BlockHeight::from_u32(2_100_000));
+        let decoded = Zewif::try_from(Envelope::from(zewif)).unwrap();
+        assert_eq!(decoded.export_height(), BlockHeight::from_u32(2_100_000));
+    }
+
+    #[test]
+    fn test_format_version_roundtrip() {
+        let zewif = Zewif::new(BlockHeight::from_u32(100));
+        assert_eq!(zewif.format_version(), ZEWIF_FORMAT_VERSION);
+        let decoded = Zewif::try_from(Envelope::from(zewif.clone())).unwrap();
+        assert_eq!(decoded.format_version(), ZEWIF_FORMAT_VERSION);
+        assert_eq!(decoded, zewif);
+    }
+
+    #[test]
+    fn test_legacy_container_upgraded_on_write() {
+        // A version 1 container: no format_version assertion, and an account written with
+        // string predicates.
+        let legacy_account = Envelope::new(0usize)
+            .add_type("Account")
+            .add_assertion("name", "Savings")
+            .add_assertion("relevant_transactions", Vec::<CBOR>::new())
+            .add_assertion("address", address("t1a"));
+        let wallet = Envelope::from(ZewifWallet::new(Network::Main))
+            .add_assertion("account", legacy_account);
+        let legacy = Envelope::new(ARID::new())
+            .add_type("Zewif")
+            .add_assertion("wallet", wallet)
+            .add_assertion("export_height", BlockHeight::from_u32(100));
+
+        let zewif = Zewif::try_from(legacy).unwrap();
+        assert_eq!(zewif.format_version(), ZEWIF_FORMAT_VERSION);
+        let account = &zewif.wallets()[0].accounts()[0];
+        assert_eq!(account.name(), "Savings");
+        assert_eq!(account.addresses_len(), 1);
+
+        let written = Envelope::from(zewif);
+        let version: u32 = written
+            .extract_object_for_predicate("format_version")
+            .unwrap();
+        assert_eq!(version, 2);
+        assert_eq!(Zewif::try_from(written).unwrap().format_version(), 2);
+    }
+
+    #[test]
+    fn test_future_format_version_rejected() {
+        let mut zewif = Zewif::new(BlockHeight::from_u32(100));
+        zewif.format_version = ZEWIF_FORMAT_VERSION + 1;
+        let error = Zewif::try_from(Envelope::from(zewif)).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("Unsupported ZeWIF format version")
+        );
+    }
+
+    #[test]
+    fn test_dedup_addresses_within_accounts() {
+        let named = |s: &str, name: &str, purpose: Option<&str>| {
+            let mut address = address(s);
+            address.set_name(name.to_string());
+            if let Some(purpose) = purpose {
+                address.set_purpose(purpose.to_string());
             }
+            address
+        };
+
+        let mut first = Account::new();
+        first.add_address(named("t1a", "", None));
+        first.add_address(named("t1b", "savings", None));
+        first.add_address(named("t1a", "spending", Some("change")));
+        first.add_address(named("t1a", "other", None));
+        let mut second = Account::new();
+        second.add_address(named("t1a", "", None));
+        let mut wallet = ZewifWallet::new(Network::Main);
+        wallet.add_account(first);
+        wallet.add_account(second);
+        let mut zewif = Zewif::new(BlockHeight::from_u32(2_000_000));
+        zewif.add_wallet(wallet);
+
+        assert_eq!(zewif.dedup_addresses_within_accounts(), 2);
+        let accounts = zewif.wallets()[0].accounts();
+        let addresses = accounts[0].addresses();
+        assert_eq!(addresses.len(), 2);
+        assert_eq!(addresses[0].as_string(), "t1a");
+        assert_eq!(addresses[0].name(), "spending");
+        assert_eq!(addresses[0].purpose(), Some("change"));
+        assert_eq!(addresses[1].as_string(), "t1b");
+        assert_eq!(addresses[1].index(), 1);
+        // The same address in another account is preserved.
+        assert_eq!(accounts[1].addresses_len(), 1);
+
+        assert_eq!(zewif.dedup_addresses_within_accounts(), 0);
+    }
+
+    #[test]
+    fn test_retain_wallets() {
+        let mut zewif = Zewif::new(BlockHeight::from_u32(2_000_000));
+        for network in [Network::Main, Network::Test, Network::Main] {
+            zewif.add_wallet(ZewifWallet::new(network));
+        }
+
+        zewif.retain_wallets(|wallet| wallet.network() == Network::Main);
+        assert_eq!(zewif.wallets_len(), 2);
+        for (index, wallet) in zewif.wallets().iter().enumerate() {
+            assert_eq!(wallet.index(), index);
+            assert_eq!(wallet.network(), Network::Main);
         }
     }
 
-    test_envelope_roundtrip!(Zewif);
+    #[test]
+    fn test_size_report() {
+        let mut zewif = Zewif::new(BlockHeight::from_u32(2_000_000));
+        for _ in 0..3 {
+            zewif.add_wallet(ZewifWallet::random());
+        }
+        for _ in 0..5 {
+            let transaction = Transaction::random();
+            zewif.add_transaction(transaction.txid(), transaction);
+        }
+
+        let report = zewif.size_report();
+        assert_eq!(report.wallet_sizes().len(), 3);
+        assert_eq!(
+            report.total_size(),
+            report.wallets_size() + report.transactions_size()
+        );
+
+        // Only the container's own fields and per-item framing are unaccounted for.
+        let full = Envelope::from(zewif).to_cbor_data().len();
+        assert!(full.abs_diff(report.total_size()) <= 128 + 32 * 8);
+    }
+
+    #[test]
+    fn test_accounts_for_transaction() {
+        let shared = TxId::from_bytes([1u8; 32]);
+        let other = TxId::from_bytes([2u8; 32]);
+        let mut zewif = Zewif::new(BlockHeight::from_u32(2_000_000));
+        for relevant in [vec![other], vec![shared], vec![other, shared]] {
+            let mut account = Account::new();
+            for txid in relevant {
+                account.add_relevant_transaction(txid);
+            }
+            let mut wallet = ZewifWallet::new(Network::Main);
+            wallet.add_account(Account::new());
+            wallet.add_account(account);
+            zewif.add_wallet(wallet);
+        }
+
+        let found = zewif.accounts_for_transaction(&shared);
+        let positions: Vec<(usize, usize)> = found
+            .iter()
+            .map(|(wallet, account)| (wallet.index(), account.index()))
+            .collect();
+        assert_eq!(positions, vec![(1, 1), (2, 1)]);
+        assert!(
+            zewif
+                .accounts_for_transaction(&TxId::from_bytes([3u8; 32]))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_visit() {
+        #[derive(Default)]
+        struct Counter {
+            wallets: usize,
+            accounts: usize,
+            addresses: usize,
+            transactions: usize,
+        }
+
+        impl ZewifVisitor for Counter {
+            fn visit_wallet(&mut self, _wallet: &ZewifWallet) {
+                self.wallets += 1;
+            }
+
+            fn visit_account(&mut self, _account: &Account) {
+                self.accounts += 1;
+            }
+
+            fn visit_address(&mut self, _address: &Address) {
+                self.addresses += 1;
+            }
+
+            fn visit_transaction(&mut self, _transaction: &Transaction) {
+                self.transactions += 1;
+            }
+        }
+
+        let zewif = Zewif::random();
+        let mut counter = Counter::default();
+        zewif.visit(&mut counter);
+
+        let accounts = zewif.wallets().iter().flat_map(|w| w.accounts());
+        assert_eq!(counter.wallets, zewif.wallets_len());
+        assert_eq!(counter.accounts, accounts.clone().count());
+        assert_eq!(
+            counter.addresses,
+            accounts.map(|a| a.addresses_len()).sum::<usize>()
+        );
+        assert_eq!(counter.transactions, zewif.transactions().len());
+    }
+
+    #[test]
+    fn test_attachment_stats() {
+        let mut first = Account::new();
+        first.add_attachment("shared", "com.example", None::<&str>);
+        let mut second = Account::new();
+        second.add_attachment("shared", "com.example", None::<&str>);
+        second.add_attachment("own", "com.example", None::<&str>);
+        let mut wallet = ZewifWallet::new(Network::Main);
+        wallet.add_account(first);
+        wallet.add_account(second);
+        let mut zewif = Zewif::new(BlockHeight::from_u32(2_000_000));
+        zewif.add_wallet(wallet);
+
+        let stats = zewif.attachment_stats();
+        assert_eq!(stats.total(), 3);
+        assert_eq!(stats.unique(), 2);
+        assert_eq!(stats.duplicates(), 1);
+
+        let before = zewif.clone();
+        zewif.dedup_attachments();
+        assert_eq!(zewif, before);
+        assert_eq!(zewif.attachment_stats(), stats);
+    }
+
+    #[test]
+    fn test_encrypted_envelope_roundtrip() {
+        let zewif = Zewif::random();
+        let envelope = zewif.to_encrypted_envelope("password").unwrap();
+        let ze = ZewifEnvelope::new(envelope.clone()).unwrap();
+        assert!(ze.is_encrypted());
+        assert_eq!(ze.id(), zewif.id());
+
+        assert_eq!(
+            Zewif::from_encrypted_envelope(envelope.clone(), "password").unwrap(),
+            zewif
+        );
+        assert!(Zewif::from_encrypted_envelope(envelope, "wrong").is_err());
+        assert!(Zewif::from_encrypted_envelope(Envelope::from(zewif), "password").is_err());
+    }
+
+    #[test]
+    fn test_find_transaction() {
+        let present = TxId::from_bytes([1u8; 32]);
+        let missing = TxId::from_bytes([2u8; 32]);
+        let mut zewif = Zewif::new(BlockHeight::from_u32(100));
+        zewif.add_transaction(present, Transaction::new(present));
+
+        assert_eq!(zewif.find_transaction(&present).unwrap().txid(), present);
+        assert!(zewif.find_transaction(&missing).is_none());
+
+        zewif
+            .find_transaction_mut(&present)
+            .unwrap()
+            .set_mined_height(BlockHeight::from_u32(90));
+        assert_eq!(
+            zewif.find_transaction(&present).unwrap().mined_height(),
+            Some(&BlockHeight::from_u32(90))
+        );
+        assert!(zewif.find_transaction_mut(&missing).is_none());
+    }
+
+    fn address(s: &str) -> Address {
+        Address::new(ProtocolAddress::Transparent(transparent::Address::new(s)))
+    }
+
+    fn diff_fixture(addresses: &[&str]) -> Zewif {
+        let txid = TxId::from_bytes([1u8; 32]);
+        let mut account = Account::new();
+        for s in addresses {
+            account.add_address(address(s));
+        }
+        account.add_relevant_transaction(txid);
+        let mut wallet = ZewifWallet::new(Network::Main);
+        wallet.add_account(account);
+
+        let mut zewif = Zewif::new(BlockHeight::from_u32(100));
+        zewif.add_wallet(wallet);
+        zewif.add_transaction(txid, Transaction::new(txid));
+        zewif
+    }
+
+    #[test]
+    fn test_diff_identical() {
+        let zewif = diff_fixture(&["t1a", "t1b"]);
+        assert!(zewif.diff(&zewif.clone()).is_empty());
+
+        // A re-imported container has a new ID and may store addresses in another order.
+        let reordered = diff_fixture(&["t1b", "t1a"]);
+        assert!(zewif.diff(&reordered).is_empty());
+    }
+
+    #[test]
+    fn test_diff_one_address() {
+        let ours = diff_fixture(&["t1a", "t1b"]);
+        let theirs = diff_fixture(&["t1a", "t1b", "t1c"]);
+
+        assert_eq!(
+            ours.diff(&theirs),
+            vec![DiffEntry::new(
+                "wallets[0].accounts[0].addresses[t1c]",
+                DiffKind::Added
+            )]
+        );
+        assert_eq!(
+            theirs.diff(&ours),
+            vec![DiffEntry::new(
+                "wallets[0].accounts[0].addresses[t1c]",
+                DiffKind::Removed
+            )]
+        );
+    }
+
+    #[test]
+    fn test_diff_duplicate_address() {
+        let named = |name: &str| {
+            let mut address = address("t1a");
+            address.set_name(name.to_string());
+            address
+        };
+        let container = |addresses: Vec<Address>| {
+            let mut account = Account::new();
+            for address in addresses {
+                account.add_address(address);
+            }
+            let mut wallet = ZewifWallet::new(Network::Main);
+            wallet.add_account(account);
+            let mut zewif = Zewif::new(BlockHeight::from_u32(100));
+            zewif.add_wallet(wallet);
+            zewif
+        };
+        let path = "wallets[0].accounts[0].addresses[t1a]";
+
+        let twice = container(vec![named("first"), named("second")]);
+        let once = container(vec![named("first")]);
+        assert_eq!(
+            twice.diff(&once),
+            vec![DiffEntry::new(path, DiffKind::Removed)]
+        );
+        assert_eq!(
+            once.diff(&twice),
+            vec![DiffEntry::new(path, DiffKind::Added)]
+        );
+
+        let renamed = container(vec![named("second")]);
+        assert_eq!(
+            twice.diff(&renamed),
+            vec![
+                DiffEntry::new(path, DiffKind::Changed),
+                DiffEntry::new(path, DiffKind::Removed),
+            ]
+        );
+        assert!(twice.diff(&twice.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_validate_clean() {
+        let txid = TxId::from_bytes([1u8; 32]);
+        let mut account = Account::new();
+        account.add_address(address("t1a"));
+        account.add_address(address("t1b"));
+        account.add_relevant_transaction(txid);
+        let mut wallet = ZewifWallet::new(Network::Main);
+        wallet.add_account(account);
+        wallet.add_account(Account::new());
+
+        let mut zewif = Zewif::new(BlockHeight::from_u32(100));
+        zewif.add_wallet(wallet);
+        zewif.add_transaction(txid, Transaction::new(txid));
+
+        assert!(zewif.validate().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_prune_orphan_transactions() {
+        let referenced = TxId::from_bytes([1u8; 32]);
+        let orphan_a = TxId::from_bytes([2u8; 32]);
+        let orphan_b = TxId::from_bytes([3u8; 32]);
+        let mut account = Account::new();
+        account.add_relevant_transaction(referenced);
+        let mut wallet = ZewifWallet::new(Network::Main);
+        wallet.add_account(account);
+
+        let mut zewif = Zewif::new(BlockHeight::from_u32(100));
+        zewif.add_wallet(wallet);
+        for txid in [referenced, orphan_a, orphan_b] {
+            zewif.add_transaction(txid, Transaction::new(txid));
+        }
+
+        assert_eq!(zewif.prune_orphan_transactions(), 2);
+        assert_eq!(zewif.transactions().len(), 1);
+        assert!(zewif.get_transaction(referenced).is_some());
+        assert_eq!(zewif.prune_orphan_transactions(), 0);
+    }
+
+    #[test]
+    fn test_validate_dangling_transaction() {
+        let txid = TxId::from_bytes([1u8; 32]);
+        let mut account = Account::new();
+        account.add_relevant_transaction(txid);
+        let mut wallet = ZewifWallet::new(Network::Main);
+        wallet.add_account(account);
+
+        let mut zewif = Zewif::new(BlockHeight::from_u32(100));
+        zewif.add_wallet(wallet);
+
+        assert_eq!(
+            zewif.validate().unwrap(),
+            vec![ValidationWarning::DanglingTransactionReference {
+                wallet_index: 0,
+                account_index: 0,
+                txid,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_duplicate_account_index() {
+        // Indexes assigned by `add_account` are always unique, so a malformed wallet has to
+        // come from a decoded envelope.
+        let mut first = Account::new();
+        first.set_name("first");
+        let mut second = Account::new();
+        second.set_name("second");
+        let wallet_envelope = Envelope::new(0usize)
+            .add_type("ZewifWallet")
+            .add_assertion("network", Network::Main)
+            .add_assertion("account", first)
+            .add_assertion("account", second);
+        let wallet = ZewifWallet::try_from(wallet_envelope).unwrap();
+
+        let mut zewif = Zewif::new(BlockHeight::from_u32(100));
+        zewif.add_wallet(wallet);
+
+        assert_eq!(
+            zewif.validate().unwrap(),
+            vec![ValidationWarning::DuplicateAccountIndex {
+                wallet_index: 0,
+                account_index: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_non_contiguous_address_index() {
+        let mut account = Account::new();
+        account.add_address(address("t1a"));
+        let mut stray = address("t1b");
+        stray.set_index(5);
+        let account_envelope = Envelope::from(account).add_assertion("address", stray);
+        let account = Account::try_from(account_envelope).unwrap();
+        let mut wallet = ZewifWallet::new(Network::Main);
+        wallet.add_account(account);
+
+        let mut zewif = Zewif::new(BlockHeight::from_u32(100));
+        zewif.add_wallet(wallet);
+
+        assert_eq!(
+            zewif.validate().unwrap(),
+            vec![ValidationWarning::NonContiguousAddressIndex {
+                wallet_index: 0,
+                account_index: 0,
+                expected: 1,
+                found: 5,
+            }]
+        );
+    }
 }
@@ -0,0 +1,37 @@
+use crate::{Account, Address, Transaction, ZewifWallet};
+
+/// A callback interface for walking a container with [`Zewif::visit`](crate::Zewif::visit).
+///
+/// Every method has an empty default implementation, so a visitor implements only the ones
+/// for the items it cares about.
+///
+/// # Examples
+/// ```
+/// # use zewif::{Address, BlockHeight, Zewif, ZewifVisitor};
+/// #[derive(Default)]
+/// struct AddressCounter(usize);
+///
+/// impl ZewifVisitor for AddressCounter {
+///     fn visit_address(&mut self, _address: &Address) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// let zewif = Zewif::new(BlockHeight::from_u32(2_000_000));
+/// let mut counter = AddressCounter::default();
+/// zewif.visit(&mut counter);
+/// assert_eq!(counter.0, 0);
+/// ```
+pub trait ZewifVisitor {
+    /// Called for each wallet, before its accounts.
+    fn visit_wallet(&mut self, _wallet: &ZewifWallet) {}
+
+    /// Called for each account, before its addresses.
+    fn visit_account(&mut self, _account: &Account) {}
+
+    /// Called for each address of an account.
+    fn visit_address(&mut self, _address: &Address) {}
+
+    /// Called for each transaction in the global transaction map, after all wallets.
+    fn visit_transaction(&mut self, _transaction: &Transaction) {}
+}
@@ -1,8 +1,12 @@
 use super::Network;
 use super::{Account, SeedMaterial};
-use crate::{Indexed, NoQuotesDebugOption, envelope_indexed_objects_for_predicate};
-use anyhow::Context;
+use crate::{
+    DiffEntry, DiffKind, Indexed, NoQuotesDebugOption, ProtocolAddress, diff_keyed,
+    envelope_indexed_objects_for_predicate, set_indexes,
+};
+use anyhow::{Context, Result, bail};
 use bc_envelope::prelude::*;
+use std::collections::BTreeMap;
 
 /// A complete Zcash wallet with multiple accounts and cryptographic key material.
 ///
@@ -95,6 +99,12 @@ impl ZewifWallet {
         self.network
     }
 
+    /// Sets the network, e.g. to correct a network that was misidentified in the source
+    /// wallet. Use [`validate_addresses`](Self::validate_addresses) to check the result.
+    pub fn set_network(&mut self, network: Network) {
+        self.network = network;
+    }
+
     pub fn seed_material(&self) -> Option<&SeedMaterial> {
         self.seed_material.as_ref()
     }
@@ -111,6 +121,87 @@ impl ZewifWallet {
         account.set_index(self.accounts.len());
         self.accounts.push(account);
     }
+
+    /// Removes duplicate addresses within each account, returning the number removed.
+    ///
+    /// See [`Account::dedup_addresses`]. An address that appears in more than one account is
+    /// left in each of them.
+    pub fn dedup_addresses_within_accounts(&mut self) -> usize {
+        self.accounts.iter_mut().map(Account::dedup_addresses).sum()
+    }
+
+    /// Removes the accounts for which `f` returns `false`, keeping the rest in order.
+    ///
+    /// The remaining accounts are re-indexed to be contiguous from 0, so an account's index
+    /// may change; anything that referred to accounts by index must be updated accordingly.
+    pub fn retain_accounts(&mut self, f: impl FnMut(&Account) -> bool) {
+        self.accounts.retain(f);
+        self.accounts = set_indexes(std::mem::take(&mut self.accounts));
+    }
+
+    /// Checks that every transparent address in the wallet's accounts was encoded for the
+    /// wallet's network.
+    ///
+    /// Testnet and regtest share transparent prefixes, so they cannot be told apart here.
+    ///
+    /// # Errors
+    /// Returns an error identifying the first transparent address that cannot be decoded or
+    /// whose Base58Check prefix belongs to a different network.
+    pub fn validate_addresses(&self) -> Result<()> {
+        let prefixes = [
+            self.network.transparent_pubkey_prefix(),
+            self.network.transparent_script_prefix(),
+        ];
+        for account in &self.accounts {
+            for address in account.addresses() {
+                let ProtocolAddress::Transparent(transparent) = address.address() else {
+                    continue;
+                };
+                let prefix = transparent.version_prefix().with_context(|| {
+                    format!(
+                        "account {} address {}",
+                        account.index(),
+                        transparent.address()
+                    )
+                })?;
+                if !prefixes.contains(&prefix) {
+                    bail!(
+                        "account {} address {} does not belong to the {} network",
+                        account.index(),
+                        transparent.address(),
+                        self.network
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Records the differences between this wallet and `other` under `path`, as part of
+    /// [`Zewif::diff`](crate::Zewif::diff).
+    ///
+    /// Accounts are matched by index. A difference in any other field is reported as a change
+    /// to the wallet itself.
+    pub(crate) fn diff_into(&self, other: &ZewifWallet, path: &str, diffs: &mut Vec<DiffEntry>) {
+        if self.network != other.network
+            || self.seed_material != other.seed_material
+            || self.attachments != other.attachments
+        {
+            diffs.push(DiffEntry::new(path, DiffKind::Changed));
+        }
+
+        fn by_index(wallet: &ZewifWallet) -> BTreeMap<usize, &Account> {
+            wallet.accounts.iter().map(|a| (a.index(), a)).collect()
+        }
+        diff_keyed(
+            path,
+            "accounts",
+            by_index(self),
+            by_index(other),
+            diffs,
+            |item, ours, theirs, diffs| ours.diff_into(theirs, item, diffs),
+        );
+    }
 }
 
 #[rustfmt::skip]
@@ -151,27 +242,97 @@ impl TryFrom<Envelope> for ZewifWallet {
     }
 }
 
+#[cfg(any(test, feature = "test-dependencies"))]
+impl crate::RandomInstance for ZewifWallet {
+    fn random() -> Self {
+        use crate::SetIndexes;
+
+        Self {
+            index: 0,
+            network: Network::random(),
+            seed_material: SeedMaterial::opt_random(),
+            accounts: Vec::random().set_indexes(),
+            attachments: Attachments::random(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use bc_envelope::Attachments;
-
-    use crate::{Network, SeedMaterial, test_envelope_roundtrip};
+    use crate::{
+        Account, Address, Indexed, Network, ProtocolAddress, test_envelope_roundtrip, transparent,
+    };
 
     use super::ZewifWallet;
 
-    impl crate::RandomInstance for ZewifWallet {
-        fn random() -> Self {
-            use crate::SetIndexes;
+    test_envelope_roundtrip!(ZewifWallet);
 
-            Self {
-                index: 0,
-                network: Network::random(),
-                seed_material: SeedMaterial::opt_random(),
-                accounts: Vec::random().set_indexes(),
-                attachments: Attachments::random(),
-            }
+    fn wallet_with(network: Network, addresses: &[&str]) -> ZewifWallet {
+        let mut account = Account::new();
+        for s in addresses {
+            account.add_address(Address::new(ProtocolAddress::Transparent(
+                transparent::Address::new(*s),
+            )));
         }
+        let mut wallet = ZewifWallet::new(network);
+        wallet.add_account(account);
+        wallet
     }
 
-    test_envelope_roundtrip!(ZewifWallet);
+    #[test]
+    fn test_retain_accounts() {
+        let mut wallet = ZewifWallet::new(Network::Main);
+        for name in ["keep: spending", "savings", "keep: donations", "old"] {
+            let mut account = Account::new();
+            account.set_name(name);
+            wallet.add_account(account);
+        }
+
+        wallet.retain_accounts(|account| account.name().starts_with("keep: "));
+        let accounts: Vec<(usize, &str)> = wallet
+            .accounts()
+            .iter()
+            .map(|account| (account.index(), account.name()))
+            .collect();
+        assert_eq!(
+            accounts,
+            vec![(0, "keep: spending"), (1, "keep: donations")]
+        );
+    }
+
+    #[test]
+    fn test_validate_addresses() {
+        let wallet = wallet_with(
+            Network::Main,
+            &[
+                "t1Hxw6JqWMnhDK5jRCieg5bFHM2qt7UtQvu",
+                "t3Jex1rKwuh1bQFRrKpKGWDcDVZ8bbQuNrB",
+            ],
+        );
+        assert!(wallet.validate_addresses().is_ok());
+    }
+
+    #[test]
+    fn test_validate_addresses_network_mismatch() {
+        let mut wallet = wallet_with(
+            Network::Main,
+            &[
+                "t1Hxw6JqWMnhDK5jRCieg5bFHM2qt7UtQvu",
+                "tm9ogR9KukTCiTKvrsSxQwFv2x1vhZTydav",
+            ],
+        );
+        let error = wallet.validate_addresses().unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("tm9ogR9KukTCiTKvrsSxQwFv2x1vhZTydav")
+        );
+
+        wallet.set_network(Network::Test);
+        assert_eq!(wallet.network(), Network::Test);
+        assert!(wallet.validate_addresses().is_err());
+
+        let wallet = wallet_with(Network::Test, &["tm9ogR9KukTCiTKvrsSxQwFv2x1vhZTydav"]);
+        assert!(wallet.validate_addresses().is_ok());
+    }
 }
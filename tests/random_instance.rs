@@ -0,0 +1,28 @@
+//! Generates random ZeWIF values the way a downstream crate would, through the public API
+//! exported by the `test-dependencies` feature.
+#![cfg(feature = "test-dependencies")]
+
+use bc_envelope::prelude::*;
+use zingolabs_zewif::{Account, RandomInstance, Transaction, Zewif};
+
+#[test]
+fn random_zewif_roundtrips() {
+    let zewif = Zewif::random();
+    let decoded = Zewif::try_from(Envelope::from(zewif.clone())).unwrap();
+    assert_eq!(decoded, zewif);
+}
+
+#[test]
+fn random_components() {
+    let account = Account::random();
+    assert_eq!(
+        Account::try_from(Envelope::from(account.clone())).unwrap(),
+        account
+    );
+
+    let transaction = Transaction::random();
+    assert_eq!(
+        Transaction::try_from(Envelope::from(transaction.clone())).unwrap(),
+        transaction
+    );
+}